@@ -0,0 +1,40 @@
+// Regenerates src/preflop/equities.bin, the embedded heads-up all-in
+// equity table for every pair of starting hand classes:
+//
+//     cargo run --release --example preflop_equities -- src/preflop/equities.bin
+//
+// The table comes from `Equities::enumerated`, which walks every board for
+// every pair of concrete hands, so it is evaluated exactly as the library
+// evaluates hands. Equities are stored as little-endian u16 in basis
+// points, row by row in `StartingHand::index` order.
+
+use std::env;
+use std::fs;
+
+use ::poker_lib::Equities;
+use ::poker_lib::StartingHand;
+
+fn main() {
+    let args = env::args().collect::<Vec<_>>();
+    let path = args.get(1).expect("output path is required");
+
+    let equities = Equities::enumerated();
+    let mut table = vec![5000u16; 169 * 169];
+
+    for a in 0..169 {
+        for b in a + 1..169 {
+            let equity = equities.equity(StartingHand::from_index(a), StartingHand::from_index(b));
+            let bp = (equity * 10_000.0).round() as u16;
+
+            table[a * 169 + b] = bp;
+            table[b * 169 + a] = 10_000 - bp;
+        }
+    }
+
+    let bytes = table
+        .iter()
+        .flat_map(|equity| equity.to_le_bytes().to_vec())
+        .collect::<Vec<_>>();
+
+    fs::write(path, bytes).unwrap();
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use ::itertools::Itertools;
+
+use crate::canonical::BoardIndex;
+use crate::canonical::Canonical;
+use crate::card::Card;
+use crate::card::Deck;
+
+impl BoardIndex {
+    pub fn new() -> Self {
+        let mut flops: HashMap<Vec<Card>, usize> = HashMap::new();
+
+        for flop in Deck::list().into_iter().combinations(3) {
+            let (groups, _) = Canonical::groups(&[flop]);
+
+            *flops.entry(groups[0].clone()).or_insert(0) += 1;
+        }
+
+        let flops = flops
+            .into_iter()
+            .sorted_by_key(|(flop, _)| {
+                flop.iter()
+                    .map(|card| Canonical::key(*card))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut turns: HashMap<(Vec<Card>, Card), usize> = HashMap::new();
+
+        for (flop, weight) in flops.iter() {
+            for turn in Deck::list().into_iter().filter(|card| !flop.contains(card)) {
+                let (groups, _) = Canonical::groups(&[flop.clone(), vec![turn]]);
+
+                *turns.entry((groups[0].clone(), groups[1][0])).or_insert(0) += weight;
+            }
+        }
+
+        let turns = turns
+            .into_iter()
+            .map(|((flop, turn), weight)| (flop, turn, weight))
+            .sorted_by_key(|(flop, turn, _)| {
+                (
+                    flop.iter()
+                        .map(|card| Canonical::key(*card))
+                        .collect::<Vec<_>>(),
+                    Canonical::key(*turn),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let flop_lookup = flops
+            .iter()
+            .enumerate()
+            .map(|(n, (flop, _))| (flop.clone(), n))
+            .collect();
+
+        let turn_lookup = turns
+            .iter()
+            .enumerate()
+            .map(|(n, (flop, turn, _))| ((flop.clone(), *turn), n))
+            .collect();
+
+        Self {
+            flops,
+            turns,
+            flop_lookup,
+            turn_lookup,
+        }
+    }
+
+    // Canonical flops with the number of raw flops each one stands for.
+    pub fn flops(&self) -> Vec<(Vec<Card>, usize)> {
+        self.flops.clone()
+    }
+
+    pub fn turns(&self) -> Vec<(Vec<Card>, Card, usize)> {
+        self.turns.clone()
+    }
+
+    pub fn flop(&self, flop: &[Card]) -> Option<usize> {
+        if flop.len() != 3 || flop.iter().collect::<HashSet<_>>().len() != 3 {
+            return None;
+        }
+
+        let (groups, _) = Canonical::groups(&[flop.to_vec()]);
+
+        self.flop_lookup.get(&groups[0]).copied()
+    }
+
+    pub fn turn(&self, flop: &[Card], turn: Card) -> Option<usize> {
+        if flop.len() != 3
+            || flop
+                .iter()
+                .chain([turn].iter())
+                .collect::<HashSet<_>>()
+                .len()
+                != 4
+        {
+            return None;
+        }
+
+        let (groups, _) = Canonical::groups(&[flop.to_vec(), vec![turn]]);
+
+        self.turn_lookup
+            .get(&(groups[0].clone(), groups[1][0]))
+            .copied()
+    }
+}
+
+impl Default for BoardIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use crate::canonical::BoardIndex;
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+
+    #[test]
+    fn test_board_index() {
+        let index = BoardIndex::new();
+
+        assert_eq!(index.flops().len(), 1755);
+        assert_eq!(
+            index
+                .flops()
+                .iter()
+                .map(|(_, weight)| weight)
+                .sum::<usize>(),
+            22100
+        );
+        assert_eq!(index.turns().len(), 63193);
+        assert_eq!(
+            index
+                .turns()
+                .iter()
+                .map(|(_, _, weight)| weight)
+                .sum::<usize>(),
+            22100 * 49
+        );
+
+        let flop = [
+            Card(Rank::Ace, Suit::Hearts),
+            Card(Rank::Seven, Suit::Hearts),
+            Card(Rank::Two, Suit::Clubs),
+        ];
+        let same = [
+            Card(Rank::Two, Suit::Diamonds),
+            Card(Rank::Ace, Suit::Spades),
+            Card(Rank::Seven, Suit::Spades),
+        ];
+
+        assert_eq!(index.flop(&flop), index.flop(&same));
+        assert_some!(index.flop(&flop));
+        assert_none!(index.flop(&flop[..2]));
+        assert_eq!(
+            index.turn(&flop, Card(Rank::King, Suit::Hearts)),
+            index.turn(&same, Card(Rank::King, Suit::Spades))
+        );
+        assert_ne!(
+            index.turn(&flop, Card(Rank::King, Suit::Hearts)),
+            index.turn(&flop, Card(Rank::King, Suit::Clubs))
+        );
+        assert_none!(index.turn(&flop, Card(Rank::Ace, Suit::Hearts)));
+    }
+}
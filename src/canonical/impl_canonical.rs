@@ -0,0 +1,156 @@
+use ::itertools::Itertools;
+
+use crate::canonical::Canonical;
+use crate::card::Card;
+use crate::card::Suit;
+
+impl Canonical {
+    // Hole cards and board are canonicalized together: the same suit
+    // permutation applies to both, but cards never move between them.
+    pub fn new(hole: &[Card], board: &[Card]) -> Self {
+        let (groups, suits) = Self::groups(&[hole.to_vec(), board.to_vec()]);
+
+        Self {
+            hole: groups[0].clone(),
+            board: groups[1].clone(),
+            suits,
+        }
+    }
+
+    pub fn hole(&self) -> Vec<Card> {
+        self.hole.clone()
+    }
+
+    pub fn board(&self) -> Vec<Card> {
+        self.board.clone()
+    }
+
+    pub fn suit(&self, original: Suit) -> Suit {
+        self.suits[original.precedence() as usize]
+    }
+
+    pub fn original(&self, canonical: Suit) -> Suit {
+        Self::suits()[self
+            .suits
+            .iter()
+            .position(|suit| *suit == canonical)
+            .unwrap()]
+    }
+
+    // Tries every suit permutation and keeps the one giving the smallest
+    // encoding, with cards in each group sorted from highest to lowest.
+    pub(crate) fn groups(groups: &[Vec<Card>]) -> (Vec<Vec<Card>>, [Suit; 4]) {
+        Self::suits()
+            .into_iter()
+            .permutations(4)
+            .map(|perm| {
+                let suits = [perm[0], perm[1], perm[2], perm[3]];
+                let mapped = groups
+                    .iter()
+                    .map(|group| {
+                        group
+                            .iter()
+                            .map(|card| Card(card.rank(), suits[card.suit().precedence() as usize]))
+                            .sorted_by_key(|card| std::cmp::Reverse(Self::key(*card)))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+
+                (mapped, suits)
+            })
+            .min_by_key(|(mapped, _)| {
+                mapped
+                    .iter()
+                    .map(|group| {
+                        group
+                            .iter()
+                            .map(|card| Self::key(*card))
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap()
+    }
+
+    pub(crate) fn key(card: Card) -> u8 {
+        card.rank() as u8 * 4 + card.suit().precedence()
+    }
+
+    fn suits() -> Vec<Suit> {
+        vec![Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::canonical::Canonical;
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+
+    #[test]
+    fn test_isomorphic() {
+        let a = Canonical::new(
+            &[
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::King, Suit::Hearts),
+            ],
+            &[
+                Card(Rank::Two, Suit::Hearts),
+                Card(Rank::Seven, Suit::Spades),
+                Card(Rank::Jack, Suit::Clubs),
+            ],
+        );
+        let b = Canonical::new(
+            &[
+                Card(Rank::King, Suit::Diamonds),
+                Card(Rank::Ace, Suit::Diamonds),
+            ],
+            &[
+                Card(Rank::Jack, Suit::Hearts),
+                Card(Rank::Two, Suit::Diamonds),
+                Card(Rank::Seven, Suit::Clubs),
+            ],
+        );
+
+        assert_eq!(a.hole(), b.hole());
+        assert_eq!(a.board(), b.board());
+        assert_eq!(a.hole()[0].suit(), a.hole()[1].suit());
+    }
+
+    #[test]
+    fn test_not_isomorphic() {
+        let a = Canonical::new(
+            &[
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::King, Suit::Hearts),
+            ],
+            &[Card(Rank::Two, Suit::Hearts)],
+        );
+        let b = Canonical::new(
+            &[
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::King, Suit::Hearts),
+            ],
+            &[Card(Rank::Two, Suit::Spades)],
+        );
+
+        assert_ne!(a.board(), b.board());
+    }
+
+    #[test]
+    fn test_permutation() {
+        let hole = [
+            Card(Rank::Ace, Suit::Hearts),
+            Card(Rank::King, Suit::Spades),
+        ];
+        let canonical = Canonical::new(&hole, &[]);
+
+        for card in hole.iter() {
+            let suit = canonical.suit(card.suit());
+
+            assert!(canonical.hole().contains(&Card(card.rank(), suit)));
+            assert_eq!(canonical.original(suit), card.suit());
+        }
+    }
+}
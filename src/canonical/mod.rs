@@ -0,0 +1,22 @@
+mod board_index;
+mod impl_canonical;
+
+use std::collections::HashMap;
+
+use crate::card::Card;
+use crate::card::Suit;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canonical {
+    pub(crate) hole: Vec<Card>,
+    pub(crate) board: Vec<Card>,
+    pub(crate) suits: [Suit; 4],
+}
+
+#[derive(Debug, Clone)]
+pub struct BoardIndex {
+    pub(crate) flops: Vec<(Vec<Card>, usize)>,
+    pub(crate) turns: Vec<(Vec<Card>, Card, usize)>,
+    pub(crate) flop_lookup: HashMap<Vec<Card>, usize>,
+    pub(crate) turn_lookup: HashMap<(Vec<Card>, Card), usize>,
+}
@@ -0,0 +1,154 @@
+use ::rand::seq::SliceRandom;
+use ::rand::SeedableRng;
+use ::rand_chacha::ChaCha8Rng;
+
+use crate::card::Card;
+use crate::card::Rank;
+use crate::card::Suit;
+
+#[derive(Debug, Clone)]
+pub struct Deck {
+    cards: Vec<Card>,
+    rng: ChaCha8Rng,
+    seed: Option<u64>,
+}
+
+impl Deck {
+    pub fn new(seed: u64) -> Self {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+        let mut cards = Self::list();
+        cards.shuffle(&mut rng);
+
+        Self {
+            cards,
+            rng,
+            seed: Some(seed),
+        }
+    }
+
+    // Deals the given cards in order, for tests that need a known deal.
+    #[cfg(test)]
+    pub(crate) fn stacked(cards: Vec<Card>) -> Self {
+        Self {
+            cards,
+            rng: ChaCha8Rng::seed_from_u64(0),
+            seed: None,
+        }
+    }
+
+    pub fn list() -> Vec<Card> {
+        Rank::list()
+            .into_iter()
+            .flat_map(|rank| Suit::list().into_iter().map(move |suit| Card(rank, suit)))
+            .collect()
+    }
+
+    // The seed the deck was shuffled with, as long as no card has been
+    // dealt, removed or shuffled in since.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    pub fn cards(&self) -> Vec<Card> {
+        self.cards.clone()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    pub fn deal(&mut self, n: usize) -> Option<Vec<Card>> {
+        if n > self.cards.len() {
+            None
+        } else {
+            self.seed = None;
+
+            Some(self.cards.drain(..n).collect())
+        }
+    }
+
+    pub fn remove(&mut self, cards: &[Card]) {
+        self.seed = None;
+        self.cards.retain(|card| !cards.contains(card));
+    }
+
+    pub fn shuffle_in(&mut self, cards: Vec<Card>) {
+        self.seed = None;
+        self.cards.extend(cards);
+        self.cards.shuffle(&mut self.rng);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::itertools::Itertools;
+
+    use std::collections::HashSet;
+
+    use super::Deck;
+    use crate::card::{Card, Rank, Suit};
+
+    #[test]
+    fn test_new() {
+        let deck = Deck::new(0);
+
+        assert_eq!(deck.len(), 52);
+        assert_eq!(deck.cards().into_iter().unique().count(), 52);
+        assert_ne!(deck.cards(), Deck::list());
+    }
+
+    #[test]
+    fn test_seed() {
+        assert_eq!(Deck::new(7).cards(), Deck::new(7).cards());
+        assert_ne!(Deck::new(7).cards(), Deck::new(8).cards());
+
+        let mut deck = Deck::new(7);
+
+        assert_eq!(deck.seed(), Some(7));
+        deck.deal(1);
+        assert_eq!(deck.seed(), None);
+    }
+
+    #[test]
+    fn test_deal() {
+        let mut deck = Deck::new(0);
+        let top = deck.cards()[..3].to_vec();
+
+        assert_eq!(deck.deal(3), Some(top));
+        assert_eq!(deck.len(), 49);
+        assert_eq!(deck.deal(50), None);
+        assert_eq!(deck.len(), 49);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut deck = Deck::new(0);
+
+        deck.remove(&[
+            Card(Rank::Ace, Suit::Spades),
+            Card(Rank::King, Suit::Spades),
+        ]);
+
+        assert_eq!(deck.len(), 50);
+        assert!(!deck.cards().contains(&Card(Rank::Ace, Suit::Spades)));
+    }
+
+    #[test]
+    fn test_shuffle_in() {
+        let mut deck = Deck::new(0);
+        let dealt = deck.deal(52).unwrap();
+
+        deck.shuffle_in(dealt[..10].to_vec());
+
+        assert_eq!(deck.len(), 10);
+        assert_eq!(
+            deck.cards().into_iter().collect::<HashSet<_>>(),
+            dealt[..10].iter().copied().collect::<HashSet<_>>()
+        );
+    }
+}
@@ -1,3 +1,5 @@
+pub mod deck;
+pub use deck::Deck;
 pub mod rank;
 pub use rank::Rank;
 pub mod suit;
@@ -21,11 +23,24 @@ impl Card {
     pub fn suit(&self) -> Suit {
         self.1
     }
+
+    // Position in `Deck::list`.
+    pub fn index(&self) -> usize {
+        self.0 as usize * 4
+            + Suit::list()
+                .iter()
+                .position(|suit| *suit == self.1)
+                .unwrap()
+    }
+
+    pub fn from_index(index: usize) -> Option<Self> {
+        Deck::list().get(index).copied()
+    }
 }
 
 impl PartialOrd for Card {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.rank().partial_cmp(&other.rank())
+        Some(self.cmp(other))
     }
 }
 
@@ -42,6 +57,7 @@ mod tests {
     use std::cmp::Ordering;
 
     use super::Card;
+    use super::Deck;
     use super::Rank;
     use super::Suit;
 
@@ -63,6 +79,16 @@ mod tests {
         assert_eq!(Card::new(Rank::Two, Suit::Diamonds).suit(), Suit::Diamonds);
     }
 
+    #[test]
+    fn test_index() {
+        for (n, card) in Deck::list().into_iter().enumerate() {
+            assert_eq!(card.index(), n);
+            assert_eq!(Card::from_index(n), Some(card));
+        }
+
+        assert_eq!(Card::from_index(52), None);
+    }
+
     #[test]
     fn test_ordering() {
         assert_eq!(
@@ -12,6 +12,16 @@ impl Suit {
     pub fn list() -> Vec<Self> {
         vec![Self::Diamonds, Self::Clubs, Self::Hearts, Self::Spades]
     }
+
+    // Bridge order, used wherever ties are broken by suit.
+    pub(crate) fn precedence(&self) -> u8 {
+        match self {
+            Self::Clubs => 0,
+            Self::Diamonds => 1,
+            Self::Hearts => 2,
+            Self::Spades => 3,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -4,6 +4,8 @@ use ::itertools::Itertools;
 
 use crate::card::Card;
 use crate::card::Rank;
+use crate::card::Suit;
+use crate::combination::Class;
 use crate::combination::Combination;
 use crate::combination::Variant;
 
@@ -21,6 +23,22 @@ impl Combination {
             .unwrap()
     }
 
+    // Low combinations have no high hand category.
+    pub fn class(&self) -> Option<Class> {
+        match self {
+            Self::HighCard { .. } => Some(Class::HighCard),
+            Self::Pair { .. } => Some(Class::Pair),
+            Self::TwoPairs { .. } => Some(Class::TwoPairs),
+            Self::ThreeOfAKind { .. } => Some(Class::ThreeOfAKind),
+            Self::Straight { .. } => Some(Class::Straight),
+            Self::Flush { .. } => Some(Class::Flush),
+            Self::FullHouse { .. } => Some(Class::FullHouse),
+            Self::FourOfAKind { .. } => Some(Class::FourOfAKind),
+            Self::StraightFlush { .. } => Some(Class::StraightFlush),
+            Self::AceToFive { .. } | Self::DeuceToSeven { .. } => None,
+        }
+    }
+
     fn try_straight_flush(variant: Variant) -> Option<Self> {
         Self::try_flush(variant)
             .and_then(|_flush| Self::try_straight(variant))
@@ -34,7 +52,7 @@ impl Combination {
     }
 
     fn try_four_of_a_kind(variant: Variant) -> Option<Self> {
-        let groups = Self::group_ranks(variant.0);
+        let groups = Self::group_ranks(&variant.0);
 
         let rank = groups
             .clone()
@@ -55,7 +73,7 @@ impl Combination {
     }
 
     fn try_full_house(variant: Variant) -> Option<Self> {
-        let groups = Self::group_ranks(variant.0);
+        let groups = Self::group_ranks(&variant.0);
 
         let three = groups
             .clone()
@@ -76,7 +94,7 @@ impl Combination {
             .map(|(three, two)| Self::FullHouse { three, two })
     }
 
-    fn try_flush(variant: Variant) -> Option<Self> {
+    pub(crate) fn try_flush(variant: Variant) -> Option<Self> {
         let cards = &variant.0;
         let suit = cards[0].1;
 
@@ -88,7 +106,7 @@ impl Combination {
         }
     }
 
-    fn try_straight(variant: Variant) -> Option<Self> {
+    pub(crate) fn try_straight(variant: Variant) -> Option<Self> {
         let ranks = variant
             .0
             .iter()
@@ -132,7 +150,7 @@ impl Combination {
     }
 
     fn try_three_of_a_kind(variant: Variant) -> Option<Self> {
-        let groups = Self::group_ranks(variant.0);
+        let groups = Self::group_ranks(&variant.0);
 
         let rank = groups
             .clone()
@@ -153,7 +171,7 @@ impl Combination {
     }
 
     fn try_two_pairs(variant: Variant) -> Option<Self> {
-        let groups = Self::group_ranks(variant.0);
+        let groups = Self::group_ranks(&variant.0);
 
         let ranks = groups
             .clone()
@@ -188,7 +206,7 @@ impl Combination {
     }
 
     fn try_pair(variant: Variant) -> Option<Self> {
-        let groups = Self::group_ranks(variant.0);
+        let groups = Self::group_ranks(&variant.0);
 
         let rank = groups
             .clone()
@@ -216,7 +234,7 @@ impl Combination {
         Some(Self::HighCard { rank })
     }
 
-    fn group_ranks(cards: [Card; 5]) -> HashMap<Rank, u64> {
+    pub(crate) fn group_ranks(cards: &[Card]) -> HashMap<Rank, u64> {
         cards
             .iter()
             .map(|card| card.0)
@@ -228,6 +246,19 @@ impl Combination {
                 acc
             })
     }
+
+    pub(crate) fn group_suits(cards: &[Card]) -> HashMap<Suit, u64> {
+        cards
+            .iter()
+            .map(|card| card.1)
+            .fold(HashMap::new(), |mut acc, x| {
+                let n = acc.get(&x).map_or(1, |n| n + 1);
+
+                acc.insert(x, n);
+
+                acc
+            })
+    }
 }
 
 #[cfg(test)]
@@ -857,7 +888,7 @@ mod tests {
     #[test]
     fn test_group_ranks() {
         assert_eq!(
-            Combination::group_ranks([
+            Combination::group_ranks(&[
                 Card(Rank::Ten, Suit::Hearts),
                 Card(Rank::King, Suit::Hearts),
                 Card(Rank::Queen, Suit::Hearts),
@@ -875,4 +906,19 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_group_suits() {
+        assert_eq!(
+            Combination::group_suits(&[
+                Card(Rank::Ten, Suit::Hearts),
+                Card(Rank::King, Suit::Spades),
+                Card(Rank::Queen, Suit::Hearts),
+            ])
+            .into_iter()
+            .sorted_by_key(|(_, n)| *n)
+            .collect::<Vec<(_, _)>>(),
+            vec![(Suit::Spades, 1), (Suit::Hearts, 2)]
+        );
+    }
 }
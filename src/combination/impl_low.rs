@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+use std::convert::TryInto;
+
+use ::itertools::Itertools;
+
+use crate::card::Rank;
+use crate::combination::Combination;
+use crate::combination::Variant;
+
+impl Combination {
+    pub(crate) fn from_variant_ace_to_five(variant: Variant) -> Self {
+        let ranks = variant.0.iter().map(|card| card.0).collect::<Vec<_>>();
+
+        Self::AceToFive {
+            ranks: Self::sort_low_ranks(&ranks).try_into().unwrap(),
+        }
+    }
+
+    pub(crate) fn from_variant_deuce_to_seven(variant: Variant) -> Self {
+        let ranks = variant.0.iter().map(|card| card.0).collect::<Vec<_>>();
+        let groups = Self::group_low_ranks(&ranks);
+
+        let ranks = ranks
+            .into_iter()
+            .sorted_by(|rank_a, rank_b| {
+                groups[rank_b].cmp(&groups[rank_a]).then(rank_b.cmp(rank_a))
+            })
+            .collect::<Vec<_>>();
+
+        Self::DeuceToSeven {
+            ranks: ranks.try_into().unwrap(),
+            flush: variant.0.iter().map(|card| card.1).all_equal(),
+        }
+    }
+
+    // Deuce-to-seven is the high ranking turned upside down: the ace is
+    // always high, and straights and flushes count against the hand.
+    pub(crate) fn deuce_to_seven_key(ranks: &[Rank; 5], flush: bool) -> (u8, [Rank; 5]) {
+        let groups = Self::group_low_ranks(ranks);
+
+        let counts = ranks
+            .iter()
+            .dedup()
+            .map(|rank| groups[rank])
+            .collect::<Vec<_>>();
+
+        let straight = counts.len() == 5 && ranks[0] as u8 - ranks[4] as u8 == 4;
+
+        let category = match (counts.as_slice(), straight, flush) {
+            (_, true, true) => 8,
+            ([4, 1], _, _) => 7,
+            ([3, 2], _, _) => 6,
+            (_, false, true) => 5,
+            (_, true, false) => 4,
+            ([3, 1, 1], _, _) => 3,
+            ([2, 2, 1], _, _) => 2,
+            ([2, 1, 1, 1], _, _) => 1,
+            _ => 0,
+        };
+
+        (category, *ranks)
+    }
+
+    pub(crate) fn is_eight_or_better(&self) -> bool {
+        match self {
+            Self::AceToFive { ranks } => {
+                ranks.iter().unique().count() == ranks.len()
+                    && ranks
+                        .iter()
+                        .all(|rank| Self::ace_low(*rank) <= Self::ace_low(Rank::Eight))
+            }
+            _ => false,
+        }
+    }
+
+    // Lower keys are better lows: pairing comes first, then the ranks from
+    // the top down with the ace counted as one.
+    pub(crate) fn ace_to_five_key(ranks: &[Rank]) -> (Vec<u64>, Vec<u8>) {
+        let groups = Self::group_low_ranks(ranks);
+        let ranks = Self::sort_low_ranks(ranks);
+
+        let counts = ranks
+            .iter()
+            .dedup()
+            .map(|rank| groups[rank])
+            .collect::<Vec<_>>();
+
+        (
+            counts,
+            ranks.into_iter().map(Self::ace_low).collect::<Vec<_>>(),
+        )
+    }
+
+    fn sort_low_ranks(ranks: &[Rank]) -> Vec<Rank> {
+        let groups = Self::group_low_ranks(ranks);
+
+        ranks
+            .iter()
+            .copied()
+            .sorted_by(|rank_a, rank_b| {
+                groups[rank_b]
+                    .cmp(&groups[rank_a])
+                    .then(Self::ace_low(*rank_b).cmp(&Self::ace_low(*rank_a)))
+            })
+            .collect::<Vec<_>>()
+    }
+
+    pub(crate) fn ace_low(rank: Rank) -> u8 {
+        match rank {
+            Rank::Ace => 0,
+            rank => rank as u8 + 1,
+        }
+    }
+
+    fn group_low_ranks(ranks: &[Rank]) -> HashMap<Rank, u64> {
+        ranks.iter().fold(HashMap::new(), |mut acc, x| {
+            let n = acc.get(x).map_or(1, |n| n + 1);
+
+            acc.insert(*x, n);
+
+            acc
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::combination::Combination;
+    use crate::combination::Variant;
+
+    #[test]
+    fn test_ace_to_five_from_variant() {
+        let variant = Variant([
+            Card(Rank::Five, Suit::Diamonds),
+            Card(Rank::Ace, Suit::Diamonds),
+            Card(Rank::Seven, Suit::Spades),
+            Card(Rank::Three, Suit::Diamonds),
+            Card(Rank::Four, Suit::Diamonds),
+        ]);
+
+        assert_eq!(
+            Combination::from_variant_ace_to_five(variant),
+            Combination::AceToFive {
+                ranks: [Rank::Seven, Rank::Five, Rank::Four, Rank::Three, Rank::Ace]
+            }
+        );
+    }
+
+    #[test]
+    fn test_ace_to_five_from_variant_paired() {
+        let variant = Variant([
+            Card(Rank::Three, Suit::Diamonds),
+            Card(Rank::Ace, Suit::Diamonds),
+            Card(Rank::Eight, Suit::Spades),
+            Card(Rank::Three, Suit::Hearts),
+            Card(Rank::Five, Suit::Diamonds),
+        ]);
+
+        assert_eq!(
+            Combination::from_variant_ace_to_five(variant),
+            Combination::AceToFive {
+                ranks: [Rank::Three, Rank::Three, Rank::Eight, Rank::Five, Rank::Ace]
+            }
+        );
+    }
+
+    #[test]
+    fn test_ace_to_five_ignores_straights_and_flushes() {
+        let wheel = Variant([
+            Card(Rank::Ace, Suit::Hearts),
+            Card(Rank::Two, Suit::Hearts),
+            Card(Rank::Three, Suit::Hearts),
+            Card(Rank::Four, Suit::Hearts),
+            Card(Rank::Five, Suit::Hearts),
+        ]);
+
+        let six = Variant([
+            Card(Rank::Ace, Suit::Hearts),
+            Card(Rank::Two, Suit::Clubs),
+            Card(Rank::Three, Suit::Hearts),
+            Card(Rank::Four, Suit::Spades),
+            Card(Rank::Six, Suit::Hearts),
+        ]);
+
+        assert_gt!(
+            Combination::from_variant_ace_to_five(wheel),
+            Combination::from_variant_ace_to_five(six)
+        );
+    }
+
+    #[test]
+    fn test_deuce_to_seven_from_variant() {
+        let variant = Variant([
+            Card(Rank::Five, Suit::Diamonds),
+            Card(Rank::Two, Suit::Diamonds),
+            Card(Rank::Seven, Suit::Spades),
+            Card(Rank::Three, Suit::Diamonds),
+            Card(Rank::Four, Suit::Diamonds),
+        ]);
+
+        assert_eq!(
+            Combination::from_variant_deuce_to_seven(variant),
+            Combination::DeuceToSeven {
+                ranks: [Rank::Seven, Rank::Five, Rank::Four, Rank::Three, Rank::Two],
+                flush: false
+            }
+        );
+    }
+
+    #[test]
+    fn test_deuce_to_seven_straights_and_flushes() {
+        let straight = Variant([
+            Card(Rank::Two, Suit::Hearts),
+            Card(Rank::Three, Suit::Clubs),
+            Card(Rank::Four, Suit::Hearts),
+            Card(Rank::Five, Suit::Spades),
+            Card(Rank::Six, Suit::Hearts),
+        ]);
+
+        let flush = Variant([
+            Card(Rank::Two, Suit::Hearts),
+            Card(Rank::Three, Suit::Hearts),
+            Card(Rank::Four, Suit::Hearts),
+            Card(Rank::Five, Suit::Hearts),
+            Card(Rank::Seven, Suit::Hearts),
+        ]);
+
+        let wheel = Variant([
+            Card(Rank::Ace, Suit::Hearts),
+            Card(Rank::Two, Suit::Clubs),
+            Card(Rank::Three, Suit::Hearts),
+            Card(Rank::Four, Suit::Spades),
+            Card(Rank::Five, Suit::Hearts),
+        ]);
+
+        let pair = Variant([
+            Card(Rank::Two, Suit::Hearts),
+            Card(Rank::Two, Suit::Clubs),
+            Card(Rank::Three, Suit::Hearts),
+            Card(Rank::Four, Suit::Spades),
+            Card(Rank::Five, Suit::Hearts),
+        ]);
+
+        assert_gt!(
+            Combination::from_variant_deuce_to_seven(wheel),
+            Combination::from_variant_deuce_to_seven(pair)
+        );
+        assert_gt!(
+            Combination::from_variant_deuce_to_seven(pair),
+            Combination::from_variant_deuce_to_seven(straight)
+        );
+        assert_gt!(
+            Combination::from_variant_deuce_to_seven(straight),
+            Combination::from_variant_deuce_to_seven(flush)
+        );
+    }
+
+    #[test]
+    fn test_eight_or_better() {
+        assert!(Combination::AceToFive {
+            ranks: [Rank::Eight, Rank::Seven, Rank::Six, Rank::Four, Rank::Ace]
+        }
+        .is_eight_or_better());
+        assert!(!Combination::AceToFive {
+            ranks: [Rank::Nine, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]
+        }
+        .is_eight_or_better());
+        assert!(!Combination::AceToFive {
+            ranks: [Rank::Two, Rank::Two, Rank::Five, Rank::Three, Rank::Ace]
+        }
+        .is_eight_or_better());
+        assert!(!Combination::HighCard { rank: Rank::Eight }.is_eight_or_better());
+    }
+
+    #[test]
+    fn test_ace_to_five_key() {
+        assert_lt!(
+            Combination::ace_to_five_key(&[Rank::King, Rank::Queen]),
+            Combination::ace_to_five_key(&[Rank::Two, Rank::Two])
+        );
+        assert_lt!(
+            Combination::ace_to_five_key(&[Rank::Ace, Rank::Four, Rank::Two]),
+            Combination::ace_to_five_key(&[Rank::Two, Rank::Five, Rank::Three])
+        );
+        assert_lt!(
+            Combination::ace_to_five_key(&[Rank::Two, Rank::Two, Rank::Three, Rank::Three]),
+            Combination::ace_to_five_key(&[Rank::Two, Rank::Two, Rank::Two, Rank::Three])
+        );
+    }
+}
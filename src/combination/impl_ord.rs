@@ -3,6 +3,9 @@ use std::cmp::Ordering;
 use crate::card::Rank;
 use crate::combination::Combination;
 
+// The full ordering lives in `partial_cmp`, which every pair of
+// combinations answers, and `cmp` unwraps it.
+#[allow(clippy::non_canonical_partial_ord_impl)]
 impl PartialOrd for Combination {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         match self {
@@ -20,6 +23,8 @@ impl PartialOrd for Combination {
                 Self::FullHouse { two: _, three: _ } => Some(Ordering::Less),
                 Self::FourOfAKind { rank: _, kicker: _ } => Some(Ordering::Less),
                 Self::StraightFlush { rank: _ } => Some(Ordering::Less),
+                Self::AceToFive { ranks: _ } => Some(Ordering::Greater),
+                Self::DeuceToSeven { ranks: _, flush: _ } => Some(Ordering::Greater),
             },
             Self::Pair {
                 rank: pair_rank_a,
@@ -44,6 +49,8 @@ impl PartialOrd for Combination {
                 Self::FullHouse { two: _, three: _ } => Some(Ordering::Less),
                 Self::FourOfAKind { rank: _, kicker: _ } => Some(Ordering::Less),
                 Self::StraightFlush { rank: _ } => Some(Ordering::Less),
+                Self::AceToFive { ranks: _ } => Some(Ordering::Greater),
+                Self::DeuceToSeven { ranks: _, flush: _ } => Some(Ordering::Greater),
             },
             Self::TwoPairs {
                 low: low_pair_rank_a,
@@ -69,6 +76,8 @@ impl PartialOrd for Combination {
                 Self::FullHouse { two: _, three: _ } => Some(Ordering::Less),
                 Self::FourOfAKind { rank: _, kicker: _ } => Some(Ordering::Less),
                 Self::StraightFlush { rank: _ } => Some(Ordering::Less),
+                Self::AceToFive { ranks: _ } => Some(Ordering::Greater),
+                Self::DeuceToSeven { ranks: _, flush: _ } => Some(Ordering::Greater),
             },
             Self::ThreeOfAKind {
                 rank: rank_a,
@@ -93,6 +102,8 @@ impl PartialOrd for Combination {
                 Self::FullHouse { two: _, three: _ } => Some(Ordering::Less),
                 Self::FourOfAKind { rank: _, kicker: _ } => Some(Ordering::Less),
                 Self::StraightFlush { rank: _ } => Some(Ordering::Less),
+                Self::AceToFive { ranks: _ } => Some(Ordering::Greater),
+                Self::DeuceToSeven { ranks: _, flush: _ } => Some(Ordering::Greater),
             },
             Self::Straight { rank: rank_a } => match other {
                 Self::HighCard { rank: _ } => Some(Ordering::Greater),
@@ -117,6 +128,8 @@ impl PartialOrd for Combination {
                 Self::FullHouse { two: _, three: _ } => Some(Ordering::Less),
                 Self::FourOfAKind { rank: _, kicker: _ } => Some(Ordering::Less),
                 Self::StraightFlush { rank: _ } => Some(Ordering::Less),
+                Self::AceToFive { ranks: _ } => Some(Ordering::Greater),
+                Self::DeuceToSeven { ranks: _, flush: _ } => Some(Ordering::Greater),
             },
             Self::Flush { rank: rank_a } => match other {
                 Self::HighCard { rank: _ } => Some(Ordering::Greater),
@@ -132,6 +145,8 @@ impl PartialOrd for Combination {
                 Self::FullHouse { two: _, three: _ } => Some(Ordering::Less),
                 Self::FourOfAKind { rank: _, kicker: _ } => Some(Ordering::Less),
                 Self::StraightFlush { rank: _ } => Some(Ordering::Less),
+                Self::AceToFive { ranks: _ } => Some(Ordering::Greater),
+                Self::DeuceToSeven { ranks: _, flush: _ } => Some(Ordering::Greater),
             },
             Self::FullHouse {
                 two: rank_two_a,
@@ -156,6 +171,8 @@ impl PartialOrd for Combination {
                 },
                 Self::FourOfAKind { rank: _, kicker: _ } => Some(Ordering::Less),
                 Self::StraightFlush { rank: _ } => Some(Ordering::Less),
+                Self::AceToFive { ranks: _ } => Some(Ordering::Greater),
+                Self::DeuceToSeven { ranks: _, flush: _ } => Some(Ordering::Greater),
             },
             Self::FourOfAKind {
                 rank: rank_a,
@@ -180,6 +197,8 @@ impl PartialOrd for Combination {
                     ord => ord,
                 },
                 Self::StraightFlush { rank: _ } => Some(Ordering::Less),
+                Self::AceToFive { ranks: _ } => Some(Ordering::Greater),
+                Self::DeuceToSeven { ranks: _, flush: _ } => Some(Ordering::Greater),
             },
             Self::StraightFlush { rank: rank_a } => match other {
                 Self::HighCard { rank: _ } => Some(Ordering::Greater),
@@ -204,6 +223,26 @@ impl PartialOrd for Combination {
                         rank_b => rank_a.partial_cmp(rank_b),
                     },
                 },
+                Self::AceToFive { ranks: _ } => Some(Ordering::Greater),
+                Self::DeuceToSeven { ranks: _, flush: _ } => Some(Ordering::Greater),
+            },
+            Self::AceToFive { ranks: ranks_a } => match other {
+                Self::AceToFive { ranks: ranks_b } => {
+                    Self::ace_to_five_key(ranks_b).partial_cmp(&Self::ace_to_five_key(ranks_a))
+                }
+                Self::DeuceToSeven { ranks: _, flush: _ } => Some(Ordering::Greater),
+                _ => Some(Ordering::Less),
+            },
+            Self::DeuceToSeven {
+                ranks: ranks_a,
+                flush: flush_a,
+            } => match other {
+                Self::DeuceToSeven {
+                    ranks: ranks_b,
+                    flush: flush_b,
+                } => Self::deuce_to_seven_key(ranks_b, *flush_b)
+                    .partial_cmp(&Self::deuce_to_seven_key(ranks_a, *flush_a)),
+                _ => Some(Ordering::Less),
             },
         }
     }
@@ -211,7 +250,7 @@ impl PartialOrd for Combination {
 
 impl Ord for Combination {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(&other).unwrap()
+        self.partial_cmp(other).unwrap()
     }
 }
 
@@ -735,4 +774,62 @@ mod tests {
         assert_eq!(lhs, Combination::StraightFlush { rank: Rank::Ace });
         assert_lt!(lhs, Combination::StraightFlush { rank: Rank::Two });
     }
+
+    #[test]
+    fn test_ordering_ace_to_five() {
+        let lhs = Combination::AceToFive {
+            ranks: [Rank::Seven, Rank::Five, Rank::Four, Rank::Three, Rank::Two],
+        };
+
+        assert_gt!(
+            lhs,
+            Combination::AceToFive {
+                ranks: [Rank::Seven, Rank::Six, Rank::Four, Rank::Three, Rank::Two],
+            }
+        );
+        assert_gt!(
+            lhs,
+            Combination::AceToFive {
+                ranks: [Rank::Two, Rank::Two, Rank::Five, Rank::Four, Rank::Three],
+            }
+        );
+        assert_lt!(
+            lhs,
+            Combination::AceToFive {
+                ranks: [Rank::Seven, Rank::Five, Rank::Four, Rank::Three, Rank::Ace],
+            }
+        );
+        assert_lt!(lhs, Combination::HighCard { rank: Rank::Two });
+    }
+
+    #[test]
+    fn test_ordering_deuce_to_seven() {
+        let lhs = Combination::DeuceToSeven {
+            ranks: [Rank::Seven, Rank::Five, Rank::Four, Rank::Three, Rank::Two],
+            flush: false,
+        };
+
+        assert_gt!(
+            lhs,
+            Combination::DeuceToSeven {
+                ranks: [Rank::Seven, Rank::Six, Rank::Four, Rank::Three, Rank::Two],
+                flush: false,
+            }
+        );
+        assert_gt!(
+            lhs,
+            Combination::DeuceToSeven {
+                ranks: [Rank::Ace, Rank::Five, Rank::Four, Rank::Three, Rank::Two],
+                flush: false,
+            }
+        );
+        assert_gt!(
+            lhs,
+            Combination::DeuceToSeven {
+                ranks: [Rank::Seven, Rank::Five, Rank::Four, Rank::Three, Rank::Two],
+                flush: true,
+            }
+        );
+        assert_lt!(lhs, Combination::HighCard { rank: Rank::Two });
+    }
 }
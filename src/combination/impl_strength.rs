@@ -0,0 +1,301 @@
+use crate::card::Card;
+use crate::combination::Class;
+use crate::combination::Combination;
+
+impl Combination {
+    // Full-precision strength of the best high hand in three to seven cards,
+    // with every kicker kept. The class sits above five rank slots, most
+    // significant first; empty slots are zero, so a three-card front row
+    // compares correctly against five-card rows.
+    pub(crate) fn strength(cards: &[Card]) -> u32 {
+        let mut ranks = 0u32;
+        let mut suits = [0u32; 4];
+        let mut counts = [0u8; 13];
+
+        for card in cards.iter() {
+            let rank = card.rank() as usize;
+
+            ranks |= 1 << rank;
+            suits[card.suit().precedence() as usize] |= 1 << rank;
+            counts[rank] += 1;
+        }
+
+        let class = |class: Class, slots: u32| (class as u32) << 20 | slots;
+
+        if let Some(flush) = suits.iter().find(|suit| suit.count_ones() >= 5) {
+            return match Self::straight_high(*flush) {
+                Some(high) => class(Class::StraightFlush, high << 16),
+                None => class(Class::Flush, Self::top(*flush, 5)),
+            };
+        }
+
+        let with = |n: u8| {
+            (0..13)
+                .filter(|rank| counts[*rank] >= n)
+                .fold(0u32, |acc, rank| acc | 1 << rank)
+        };
+
+        let (quads, trips, pairs) = (with(4), with(3), with(2));
+
+        if quads != 0 {
+            let quad = Self::highest(quads);
+
+            return class(
+                Class::FourOfAKind,
+                (quad + 1) << 16 | Self::top(ranks & !(1 << quad), 1) << 12,
+            );
+        }
+
+        if trips != 0 {
+            let trip = Self::highest(trips);
+            let pair = pairs & !(1 << trip);
+
+            if pair != 0 {
+                return class(
+                    Class::FullHouse,
+                    (trip + 1) << 16 | (Self::highest(pair) + 1) << 12,
+                );
+            }
+        }
+
+        if let Some(high) = Self::straight_high(ranks) {
+            return class(Class::Straight, high << 16);
+        }
+
+        if trips != 0 {
+            let trip = Self::highest(trips);
+
+            return class(
+                Class::ThreeOfAKind,
+                (trip + 1) << 16 | Self::top(ranks & !(1 << trip), 2) << 8,
+            );
+        }
+
+        match pairs.count_ones() {
+            0 => class(Class::HighCard, Self::top(ranks, 5)),
+            1 => class(
+                Class::Pair,
+                (Self::highest(pairs) + 1) << 16 | Self::top(ranks & !pairs, 3) << 4,
+            ),
+            _ => {
+                let high = Self::highest(pairs);
+                let low = Self::highest(pairs & !(1 << high));
+                let used = (1 << high) | (1 << low);
+
+                class(
+                    Class::TwoPairs,
+                    (high + 1) << 16 | (low + 1) << 12 | Self::top(ranks & !used, 1) << 8,
+                )
+            }
+        }
+    }
+
+    // Drops the kicker slots, keeping the class and the ranks that make it.
+    pub(crate) fn without_kickers(strength: u32) -> u32 {
+        // Slots that make each class, indexed like `Class`.
+        const MADE: [u32; 9] = [
+            0xfffff, 0xf0000, 0xff000, 0xf0000, 0xfffff, 0xfffff, 0xff000, 0xf0000, 0xfffff,
+        ];
+
+        strength & (0xf << 20 | MADE[(strength >> 20) as usize])
+    }
+
+    // The top card of a straight plus one, with the wheel topped by the five.
+    fn straight_high(mask: u32) -> Option<u32> {
+        let mask = (mask << 1) | ((mask >> 12) & 1);
+
+        (4..=13)
+            .rev()
+            .find(|high| (mask >> (high - 4)) & 0x1f == 0x1f)
+    }
+
+    fn highest(mask: u32) -> u32 {
+        31 - mask.leading_zeros()
+    }
+
+    // The `n` highest ranks in the mask, one slot each, padded with zeros.
+    fn top(mask: u32, n: usize) -> u32 {
+        let (slots, taken) = (0..13)
+            .rev()
+            .filter(|rank| mask & (1 << rank) != 0)
+            .take(n)
+            .fold((0, 0), |(slots, taken), rank| {
+                (slots << 4 | (rank + 1), taken + 1)
+            });
+
+        slots << (4 * (n - taken))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::combination::Combination;
+    use crate::combination::Variant;
+
+    fn strength(cards: &[(Rank, Suit)]) -> u32 {
+        Combination::strength(
+            &cards
+                .iter()
+                .map(|(rank, suit)| Card(*rank, *suit))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[test]
+    fn test_kickers() {
+        assert!(
+            strength(&[
+                (Rank::Ace, Suit::Clubs),
+                (Rank::King, Suit::Hearts),
+                (Rank::Queen, Suit::Spades),
+                (Rank::Nine, Suit::Clubs),
+                (Rank::Three, Suit::Diamonds),
+            ]) > strength(&[
+                (Rank::Ace, Suit::Hearts),
+                (Rank::King, Suit::Spades),
+                (Rank::Jack, Suit::Clubs),
+                (Rank::Ten, Suit::Diamonds),
+                (Rank::Nine, Suit::Hearts),
+            ])
+        );
+        assert!(
+            strength(&[
+                (Rank::Eight, Suit::Clubs),
+                (Rank::Eight, Suit::Hearts),
+                (Rank::Ace, Suit::Spades),
+                (Rank::Four, Suit::Clubs),
+                (Rank::Three, Suit::Diamonds),
+            ]) > strength(&[
+                (Rank::Eight, Suit::Diamonds),
+                (Rank::Eight, Suit::Spades),
+                (Rank::King, Suit::Clubs),
+                (Rank::Queen, Suit::Diamonds),
+                (Rank::Jack, Suit::Hearts),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_best_of_seven() {
+        assert_eq!(
+            strength(&[
+                (Rank::Ace, Suit::Clubs),
+                (Rank::Two, Suit::Hearts),
+                (Rank::Three, Suit::Spades),
+                (Rank::Four, Suit::Clubs),
+                (Rank::Five, Suit::Diamonds),
+                (Rank::King, Suit::Diamonds),
+                (Rank::King, Suit::Hearts),
+            ]) >> 20,
+            4
+        );
+        assert!(
+            strength(&[
+                (Rank::Ace, Suit::Clubs),
+                (Rank::Two, Suit::Hearts),
+                (Rank::Three, Suit::Spades),
+                (Rank::Four, Suit::Clubs),
+                (Rank::Five, Suit::Diamonds),
+            ]) < strength(&[
+                (Rank::Two, Suit::Hearts),
+                (Rank::Three, Suit::Spades),
+                (Rank::Four, Suit::Clubs),
+                (Rank::Five, Suit::Diamonds),
+                (Rank::Six, Suit::Diamonds),
+            ])
+        );
+        assert_eq!(
+            strength(&[
+                (Rank::Nine, Suit::Clubs),
+                (Rank::Nine, Suit::Hearts),
+                (Rank::Nine, Suit::Spades),
+                (Rank::Four, Suit::Clubs),
+                (Rank::Four, Suit::Diamonds),
+                (Rank::Four, Suit::Hearts),
+                (Rank::Two, Suit::Hearts),
+            ]) >> 20,
+            6
+        );
+    }
+
+    #[test]
+    fn test_without_kickers() {
+        let made = |cards: &[(Rank, Suit)]| Combination::without_kickers(strength(cards));
+
+        assert_eq!(
+            made(&[
+                (Rank::Nine, Suit::Clubs),
+                (Rank::Nine, Suit::Hearts),
+                (Rank::Ace, Suit::Spades),
+                (Rank::Two, Suit::Clubs),
+            ]),
+            made(&[
+                (Rank::Nine, Suit::Clubs),
+                (Rank::Nine, Suit::Hearts),
+                (Rank::Two, Suit::Clubs),
+            ])
+        );
+        assert!(
+            made(&[
+                (Rank::Two, Suit::Hearts),
+                (Rank::Three, Suit::Spades),
+                (Rank::Four, Suit::Clubs),
+                (Rank::Five, Suit::Diamonds),
+                (Rank::Six, Suit::Diamonds),
+            ]) > made(&[
+                (Rank::Ace, Suit::Hearts),
+                (Rank::Two, Suit::Hearts),
+                (Rank::Three, Suit::Spades),
+                (Rank::Four, Suit::Clubs),
+                (Rank::Five, Suit::Diamonds),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_agrees_with_combination_class() {
+        let hands: Vec<Vec<Card>> = vec![
+            vec![
+                Card(Rank::Ten, Suit::Spades),
+                Card(Rank::Jack, Suit::Spades),
+                Card(Rank::Queen, Suit::Spades),
+                Card(Rank::King, Suit::Spades),
+                Card(Rank::Ace, Suit::Spades),
+            ],
+            vec![
+                Card(Rank::Two, Suit::Spades),
+                Card(Rank::Two, Suit::Hearts),
+                Card(Rank::Two, Suit::Clubs),
+                Card(Rank::Two, Suit::Diamonds),
+                Card(Rank::Ace, Suit::Spades),
+            ],
+            vec![
+                Card(Rank::Two, Suit::Spades),
+                Card(Rank::Seven, Suit::Spades),
+                Card(Rank::Nine, Suit::Spades),
+                Card(Rank::King, Suit::Spades),
+                Card(Rank::Ace, Suit::Spades),
+            ],
+            vec![
+                Card(Rank::Two, Suit::Spades),
+                Card(Rank::Two, Suit::Hearts),
+                Card(Rank::Nine, Suit::Spades),
+                Card(Rank::Nine, Suit::Clubs),
+                Card(Rank::Ace, Suit::Spades),
+            ],
+        ];
+
+        for cards in hands {
+            let class = Combination::from_variant(Variant::try_from(cards.clone()).unwrap())
+                .class()
+                .unwrap();
+
+            assert_eq!(Combination::strength(&cards) >> 20, class as u32);
+        }
+    }
+}
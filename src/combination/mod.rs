@@ -3,7 +3,9 @@ use std::convert::TryInto;
 use std::hash::Hash;
 
 mod impl_combination;
+mod impl_low;
 mod impl_ord;
+mod impl_strength;
 
 use crate::card::Card;
 use crate::card::Rank;
@@ -19,6 +21,22 @@ pub enum Combination {
     FullHouse { two: Rank, three: Rank },
     FourOfAKind { rank: Rank, kicker: Rank },
     StraightFlush { rank: Rank },
+    AceToFive { ranks: [Rank; 5] },
+    DeuceToSeven { ranks: [Rank; 5], flush: bool },
+}
+
+// High hand categories, weakest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Class {
+    HighCard,
+    Pair,
+    TwoPairs,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -0,0 +1,181 @@
+use std::cmp::Ordering;
+use std::hash::Hash;
+use std::hash::Hasher;
+
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::card::Rank;
+use crate::combination::Combination;
+use crate::game::Hand;
+use crate::game::HandOf4;
+
+#[derive(Debug, Clone)]
+pub struct Badugi(Vec<Card>);
+
+impl Badugi {
+    pub fn from_hand(hand: HandOf4) -> Self {
+        (1..=4)
+            .rev()
+            .flat_map(|n| hand.cards().into_iter().combinations(n))
+            .filter(|cards| {
+                cards.iter().map(|card| card.rank()).unique().count() == cards.len()
+                    && cards.iter().map(|card| card.suit()).unique().count() == cards.len()
+            })
+            .map(|cards| {
+                Self(
+                    cards
+                        .into_iter()
+                        .sorted_by(|card_a, card_b| {
+                            Combination::ace_low(card_b.rank())
+                                .cmp(&Combination::ace_low(card_a.rank()))
+                        })
+                        .collect(),
+                )
+            })
+            .max()
+            .unwrap()
+    }
+
+    pub fn cards(&self) -> Vec<Card> {
+        self.0.clone()
+    }
+
+    pub fn ranks(&self) -> Vec<Rank> {
+        self.0.iter().map(|card| card.rank()).collect()
+    }
+
+    fn key(&self) -> (usize, Vec<u8>) {
+        (
+            self.0.len(),
+            self.0
+                .iter()
+                .map(|card| Combination::ace_low(card.rank()))
+                .collect(),
+        )
+    }
+}
+
+impl PartialEq for Badugi {
+    fn eq(&self, other: &Self) -> bool {
+        self.ranks() == other.ranks()
+    }
+}
+
+impl Eq for Badugi {}
+
+impl Hash for Badugi {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ranks().hash(state);
+    }
+}
+
+impl PartialOrd for Badugi {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Badugi {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (len_a, ranks_a) = self.key();
+        let (len_b, ranks_b) = other.key();
+
+        len_a.cmp(&len_b).then(ranks_b.cmp(&ranks_a))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use super::Badugi;
+    use crate::card::{Card, Rank, Suit};
+    use crate::game::HandOf4;
+
+    #[test]
+    fn test_four_card_badugi() {
+        let badugi = Badugi::from_hand(HandOf4::new([
+            Card(Rank::Two, Suit::Clubs),
+            Card(Rank::Ace, Suit::Diamonds),
+            Card(Rank::Seven, Suit::Hearts),
+            Card(Rank::Four, Suit::Spades),
+        ]));
+
+        assert_eq!(
+            badugi.cards(),
+            vec![
+                Card(Rank::Seven, Suit::Hearts),
+                Card(Rank::Four, Suit::Spades),
+                Card(Rank::Two, Suit::Clubs),
+                Card(Rank::Ace, Suit::Diamonds),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_three_card_badugi() {
+        let badugi = Badugi::from_hand(HandOf4::new([
+            Card(Rank::Two, Suit::Clubs),
+            Card(Rank::Three, Suit::Clubs),
+            Card(Rank::Seven, Suit::Hearts),
+            Card(Rank::Four, Suit::Spades),
+        ]));
+
+        assert_eq!(
+            badugi.cards(),
+            vec![
+                Card(Rank::Seven, Suit::Hearts),
+                Card(Rank::Four, Suit::Spades),
+                Card(Rank::Two, Suit::Clubs),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_one_card_badugi() {
+        let badugi = Badugi::from_hand(HandOf4::new([
+            Card(Rank::King, Suit::Clubs),
+            Card(Rank::King, Suit::Diamonds),
+            Card(Rank::King, Suit::Hearts),
+            Card(Rank::King, Suit::Spades),
+        ]));
+
+        assert_eq!(badugi.ranks(), vec![Rank::King]);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let four = Badugi::from_hand(HandOf4::new([
+            Card(Rank::King, Suit::Clubs),
+            Card(Rank::Queen, Suit::Diamonds),
+            Card(Rank::Jack, Suit::Hearts),
+            Card(Rank::Ten, Suit::Spades),
+        ]));
+
+        let three = Badugi::from_hand(HandOf4::new([
+            Card(Rank::Ace, Suit::Clubs),
+            Card(Rank::Two, Suit::Clubs),
+            Card(Rank::Three, Suit::Hearts),
+            Card(Rank::Four, Suit::Spades),
+        ]));
+
+        let wheel = Badugi::from_hand(HandOf4::new([
+            Card(Rank::Ace, Suit::Clubs),
+            Card(Rank::Two, Suit::Diamonds),
+            Card(Rank::Three, Suit::Hearts),
+            Card(Rank::Four, Suit::Spades),
+        ]));
+
+        let same_wheel = Badugi::from_hand(HandOf4::new([
+            Card(Rank::Ace, Suit::Spades),
+            Card(Rank::Two, Suit::Hearts),
+            Card(Rank::Three, Suit::Diamonds),
+            Card(Rank::Four, Suit::Clubs),
+        ]));
+
+        assert_gt!(four, three);
+        assert_gt!(wheel, four);
+        assert_eq!(wheel, same_wheel);
+    }
+}
@@ -0,0 +1,264 @@
+use std::io::Error;
+use std::io::ErrorKind;
+
+use crate::card::Card;
+use crate::combination::Combination;
+use crate::game::Board;
+use crate::game::Game;
+use crate::game::Hand;
+use crate::game::HandOf2;
+use crate::game::HandOf4;
+use crate::table::OddChip;
+use crate::table::Pot;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DoubleBoard {
+    TexasHoldem([Board; 2], Vec<HandOf2>),
+    OmahaHoldem([Board; 2], Vec<HandOf4>),
+}
+
+impl DoubleBoard {
+    pub fn games(&self) -> [Game; 2] {
+        match self {
+            Self::TexasHoldem([first, second], hands) => [
+                Game::TexasHoldem(*first, hands.clone()),
+                Game::TexasHoldem(*second, hands.clone()),
+            ],
+            Self::OmahaHoldem([first, second], hands) => [
+                Game::OmahaHoldem(*first, hands.clone()),
+                Game::OmahaHoldem(*second, hands.clone()),
+            ],
+        }
+    }
+
+    // Each board is ranked with `Game::rank_hands` and decided by the full
+    // strength of the reported five cards, so only hands that tie on every
+    // kicker split it. Places list seats weakest first.
+    pub fn places(&self) -> [Vec<Vec<usize>>; 2] {
+        let hands = self.hands();
+
+        self.games().map(|game| {
+            Pot::places(
+                Game::sort_hands(Game::group_hands(
+                    Game::rank_hands(game)
+                        .into_iter()
+                        .map(|(hand, _combination, variant)| {
+                            (hand, Combination::strength(&variant.cards()))
+                        })
+                        .collect(),
+                )),
+                &hands,
+            )
+        })
+    }
+
+    pub fn winners(&self) -> [Vec<usize>; 2] {
+        self.places()
+            .map(|places| places.last().cloned().unwrap_or_default())
+    }
+
+    // Odd chips of each half go by the `OddChip` rule, with `order` listing
+    // the seats from the first one left of the button.
+    pub fn distribute(
+        &self,
+        pot: u64,
+        odd_chip: OddChip,
+        order: &[usize],
+    ) -> Result<Vec<u64>, Error> {
+        let hands = self.hands();
+
+        if hands.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "no hands"));
+        }
+
+        if order.len() != hands.len() || (0..hands.len()).any(|seat| !order.contains(&seat)) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "order has to list every seat once",
+            ));
+        }
+
+        let eligible = (0..hands.len()).collect::<Vec<_>>();
+        let [first, second] = self.places();
+
+        Ok([(first, pot - pot / 2), (second, pot / 2)].iter().fold(
+            vec![0; hands.len()],
+            |mut acc, (places, amount)| {
+                let pots = [Pot {
+                    amount: *amount,
+                    eligible: eligible.clone(),
+                }];
+
+                Pot::award(&pots, places, odd_chip, order, &hands)
+                    .into_iter()
+                    .enumerate()
+                    .for_each(|(seat, won)| acc[seat] += won);
+
+                acc
+            },
+        ))
+    }
+
+    fn hands(&self) -> Vec<Vec<Card>> {
+        match self {
+            Self::TexasHoldem(_, hands) => hands.iter().map(|hand| hand.cards()).collect(),
+            Self::OmahaHoldem(_, hands) => hands.iter().map(|hand| hand.cards()).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use super::DoubleBoard;
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::game::Board;
+    use crate::game::HandOf2;
+    use crate::game::HandOf4;
+    use crate::table::OddChip;
+
+    fn boards() -> [Board; 2] {
+        [
+            Board::new([
+                Card(Rank::Ace, Suit::Spades),
+                Card(Rank::Seven, Suit::Diamonds),
+                Card(Rank::Two, Suit::Clubs),
+                Card(Rank::Nine, Suit::Hearts),
+                Card(Rank::Four, Suit::Spades),
+            ]),
+            Board::new([
+                Card(Rank::King, Suit::Clubs),
+                Card(Rank::Eight, Suit::Diamonds),
+                Card(Rank::Three, Suit::Hearts),
+                Card(Rank::Jack, Suit::Clubs),
+                Card(Rank::Five, Suit::Diamonds),
+            ]),
+        ]
+    }
+
+    #[test]
+    fn test_texas_holdem_winners() {
+        let game = DoubleBoard::TexasHoldem(
+            boards(),
+            vec![
+                HandOf2::new([Card(Rank::Ace, Suit::Hearts), Card(Rank::Ten, Suit::Clubs)]),
+                HandOf2::new([Card(Rank::King, Suit::Hearts), Card(Rank::Six, Suit::Clubs)]),
+                HandOf2::new([
+                    Card(Rank::Queen, Suit::Hearts),
+                    Card(Rank::Queen, Suit::Clubs),
+                ]),
+            ],
+        );
+
+        assert_eq!(game.winners(), [vec![0], vec![1]]);
+        assert_eq!(
+            game.distribute(101, OddChip::LeftOfButton, &[1, 2, 0])
+                .unwrap(),
+            vec![51, 50, 0]
+        );
+    }
+
+    #[test]
+    fn test_texas_holdem_split() {
+        let game = DoubleBoard::TexasHoldem(
+            boards(),
+            vec![
+                HandOf2::new([Card(Rank::Ace, Suit::Hearts), Card(Rank::Ten, Suit::Clubs)]),
+                HandOf2::new([Card(Rank::Ace, Suit::Clubs), Card(Rank::Ten, Suit::Spades)]),
+                HandOf2::new([
+                    Card(Rank::King, Suit::Hearts),
+                    Card(Rank::King, Suit::Spades),
+                ]),
+            ],
+        );
+
+        assert_eq!(game.winners(), [vec![0, 1], vec![2]]);
+        assert_eq!(
+            game.distribute(103, OddChip::LeftOfButton, &[1, 2, 0])
+                .unwrap(),
+            vec![26, 26, 51]
+        );
+        assert_eq!(
+            game.distribute(102, OddChip::LeftOfButton, &[1, 2, 0])
+                .unwrap(),
+            vec![25, 26, 51]
+        );
+        assert_eq!(
+            game.distribute(102, OddChip::BySuit, &[1, 2, 0]).unwrap(),
+            vec![26, 25, 51]
+        );
+        assert_eq!(game.places()[0], vec![vec![2], vec![0, 1]]);
+    }
+
+    #[test]
+    fn test_texas_holdem_kickers() {
+        let game = DoubleBoard::TexasHoldem(
+            boards(),
+            vec![
+                HandOf2::new([Card(Rank::Ace, Suit::Hearts), Card(Rank::Ten, Suit::Clubs)]),
+                HandOf2::new([Card(Rank::Ace, Suit::Clubs), Card(Rank::Six, Suit::Spades)]),
+            ],
+        );
+
+        assert_eq!(game.winners(), [vec![0], vec![0]]);
+        assert_eq!(
+            game.distribute(100, OddChip::LeftOfButton, &[1, 0])
+                .unwrap(),
+            vec![100, 0]
+        );
+    }
+
+    #[test]
+    fn test_omaha_holdem_winners() {
+        let game = DoubleBoard::OmahaHoldem(
+            boards(),
+            vec![
+                HandOf4::new([
+                    Card(Rank::Ace, Suit::Hearts),
+                    Card(Rank::Ace, Suit::Clubs),
+                    Card(Rank::Two, Suit::Hearts),
+                    Card(Rank::Three, Suit::Clubs),
+                ]),
+                HandOf4::new([
+                    Card(Rank::Jack, Suit::Hearts),
+                    Card(Rank::Jack, Suit::Spades),
+                    Card(Rank::Six, Suit::Hearts),
+                    Card(Rank::Six, Suit::Clubs),
+                ]),
+            ],
+        );
+
+        assert_eq!(game.winners(), [vec![0], vec![1]]);
+        assert_eq!(
+            game.distribute(100, OddChip::LeftOfButton, &[1, 0])
+                .unwrap(),
+            vec![50, 50]
+        );
+    }
+
+    #[test]
+    fn test_distribute_no_hands() {
+        assert_err!(DoubleBoard::TexasHoldem(boards(), vec![]).distribute(
+            100,
+            OddChip::LeftOfButton,
+            &[]
+        ));
+    }
+
+    #[test]
+    fn test_distribute_invalid_order() {
+        let game = DoubleBoard::TexasHoldem(
+            boards(),
+            vec![
+                HandOf2::new([Card(Rank::Ace, Suit::Hearts), Card(Rank::Ten, Suit::Clubs)]),
+                HandOf2::new([Card(Rank::King, Suit::Hearts), Card(Rank::Six, Suit::Clubs)]),
+            ],
+        );
+
+        assert_err!(game.distribute(100, OddChip::LeftOfButton, &[0]));
+        assert_err!(game.distribute(100, OddChip::LeftOfButton, &[0, 0]));
+    }
+}
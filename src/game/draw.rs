@@ -0,0 +1,323 @@
+use std::convert::TryFrom;
+use std::io::Error;
+use std::io::ErrorKind;
+
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::card::Deck;
+use crate::game::Badugi;
+use crate::game::Game;
+use crate::game::HandOf4;
+use crate::game::HandOf5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DrawGame {
+    DeuceToSevenSingleDraw,
+    DeuceToSevenTripleDraw,
+    Badugi,
+}
+
+impl DrawGame {
+    pub fn draws(&self) -> usize {
+        match self {
+            Self::DeuceToSevenSingleDraw => 1,
+            Self::DeuceToSevenTripleDraw => 3,
+            Self::Badugi => 3,
+        }
+    }
+
+    pub fn hand_size(&self) -> usize {
+        match self {
+            Self::DeuceToSevenSingleDraw => 5,
+            Self::DeuceToSevenTripleDraw => 5,
+            Self::Badugi => 4,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Draw {
+    game: DrawGame,
+    deck: Deck,
+    discards: Vec<Card>,
+    hands: Vec<Vec<Card>>,
+    round: usize,
+    drawn: Vec<bool>,
+}
+
+impl Draw {
+    pub fn new(game: DrawGame, mut deck: Deck, players: usize) -> Result<Self, Error> {
+        if players < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "at least two players are required",
+            ));
+        }
+
+        if players * game.hand_size() > deck.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "not enough cards to deal",
+            ));
+        }
+
+        let mut hands = vec![vec![]; players];
+
+        for _ in 0..game.hand_size() {
+            for hand in hands.iter_mut() {
+                hand.extend(deck.deal(1).unwrap());
+            }
+        }
+
+        Ok(Self {
+            game,
+            deck,
+            discards: vec![],
+            hands,
+            round: 0,
+            drawn: vec![false; players],
+        })
+    }
+
+    pub fn game(&self) -> DrawGame {
+        self.game
+    }
+
+    pub fn hands(&self) -> Vec<Vec<Card>> {
+        self.hands.clone()
+    }
+
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.round == self.game.draws()
+    }
+
+    pub fn draw(&mut self, player: usize, discards: Vec<Card>) -> Result<Vec<Card>, Error> {
+        if self.is_complete() {
+            return Err(Error::new(ErrorKind::InvalidInput, "no draws left"));
+        }
+
+        let hand = self
+            .hands
+            .get(player)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "no such player"))?;
+
+        if self.drawn[player] {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "player has already drawn this round",
+            ));
+        }
+
+        if discards.iter().unique().count() != discards.len()
+            || !discards.iter().all(|card| hand.contains(card))
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "discarded card is not in hand",
+            ));
+        }
+
+        if self.deck.len() < discards.len() {
+            self.deck.shuffle_in(self.discards.drain(..).collect());
+        }
+
+        let cards = self
+            .deck
+            .deal(discards.len())
+            .ok_or_else(|| Error::new(ErrorKind::UnexpectedEof, "not enough cards to draw"))?;
+
+        let hand = &mut self.hands[player];
+        hand.retain(|card| !discards.contains(card));
+        hand.extend(cards.iter().copied());
+
+        self.discards.extend(discards);
+        self.drawn[player] = true;
+
+        if self.drawn.iter().all(|drawn| *drawn) {
+            self.round += 1;
+            self.drawn = vec![false; self.hands.len()];
+        }
+
+        Ok(cards)
+    }
+
+    pub fn showdown(&self) -> Vec<Vec<Vec<Card>>> {
+        match self.game {
+            DrawGame::DeuceToSevenSingleDraw | DrawGame::DeuceToSevenTripleDraw => {
+                let game = Game::DeuceToSevenDraw(
+                    self.hands
+                        .iter()
+                        .map(|hand| HandOf5::try_from(hand.clone()).unwrap())
+                        .collect(),
+                );
+
+                Game::sort_hands(Game::group_hands(
+                    Game::rank_hands(game)
+                        .into_iter()
+                        .map(|(hand, combination, _variant)| (hand, combination))
+                        .collect(),
+                ))
+            }
+            DrawGame::Badugi => Game::sort_hands(Game::group_hands(
+                self.hands
+                    .iter()
+                    .map(|hand| {
+                        (
+                            hand.clone(),
+                            Badugi::from_hand(HandOf4::try_from(hand.clone()).unwrap()),
+                        )
+                    })
+                    .collect(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+    use ::itertools::Itertools;
+
+    use super::{Draw, DrawGame};
+    use crate::card::Card;
+    use crate::card::Deck;
+    use crate::card::Rank;
+    use crate::card::Suit;
+
+    #[test]
+    fn test_new() {
+        let draw = Draw::new(DrawGame::DeuceToSevenTripleDraw, Deck::new(0), 6).unwrap();
+
+        assert_eq!(draw.hands().len(), 6);
+        assert!(draw.hands().iter().all(|hand| hand.len() == 5));
+        assert_eq!(draw.hands().concat().into_iter().unique().count(), 30);
+        assert_eq!(draw.round(), 0);
+    }
+
+    #[test]
+    fn test_draw() {
+        let mut draw = Draw::new(DrawGame::DeuceToSevenSingleDraw, Deck::new(0), 2).unwrap();
+        let hand = draw.hands()[0].clone();
+
+        let cards = draw.draw(0, hand[..2].to_vec()).unwrap();
+
+        assert_eq!(cards.len(), 2);
+        assert_eq!(draw.hands()[0], [hand[2..].to_vec(), cards].concat());
+        assert_err!(draw.draw(0, vec![]));
+        assert_err!(draw.draw(1, hand[..1].to_vec()));
+        assert_err!(draw.draw(2, vec![]));
+
+        assert_ok!(draw.draw(1, vec![]));
+        assert!(draw.is_complete());
+        assert_err!(draw.draw(0, vec![]));
+    }
+
+    #[test]
+    fn test_draw_rounds() {
+        let mut draw = Draw::new(DrawGame::DeuceToSevenTripleDraw, Deck::new(0), 2).unwrap();
+
+        for round in 0..3 {
+            assert_eq!(draw.round(), round);
+            assert_ok!(draw.draw(0, vec![]));
+            assert_ok!(draw.draw(1, vec![]));
+        }
+
+        assert!(draw.is_complete());
+    }
+
+    #[test]
+    fn test_reshuffle_discards() {
+        let mut draw = Draw::new(DrawGame::DeuceToSevenTripleDraw, Deck::new(0), 6).unwrap();
+
+        for _ in 0..3 {
+            for player in 0..6 {
+                let hand = draw.hands()[player].clone();
+                assert_ok!(draw.draw(player, hand));
+            }
+        }
+
+        let cards = draw.hands().concat();
+
+        assert_eq!(cards.len(), 30);
+        assert_eq!(cards.into_iter().unique().count(), 30);
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        assert_err!(Draw::new(DrawGame::DeuceToSevenSingleDraw, Deck::new(0), 1));
+        assert_err!(Draw::new(
+            DrawGame::DeuceToSevenSingleDraw,
+            Deck::new(0),
+            11
+        ));
+        assert_ok!(Draw::new(
+            DrawGame::DeuceToSevenSingleDraw,
+            Deck::new(0),
+            10
+        ));
+    }
+
+    #[test]
+    fn test_showdown() {
+        let deck = Deck::stacked(vec![
+            Card(Rank::Seven, Suit::Clubs),
+            Card(Rank::Eight, Suit::Clubs),
+            Card(Rank::King, Suit::Clubs),
+            Card(Rank::Five, Suit::Diamonds),
+            Card(Rank::Six, Suit::Diamonds),
+            Card(Rank::King, Suit::Diamonds),
+            Card(Rank::Four, Suit::Hearts),
+            Card(Rank::Four, Suit::Clubs),
+            Card(Rank::Five, Suit::Clubs),
+            Card(Rank::Three, Suit::Spades),
+            Card(Rank::Three, Suit::Hearts),
+            Card(Rank::Four, Suit::Diamonds),
+            Card(Rank::Two, Suit::Diamonds),
+            Card(Rank::Two, Suit::Spades),
+            Card(Rank::Three, Suit::Diamonds),
+        ]);
+        let draw = Draw::new(DrawGame::DeuceToSevenSingleDraw, deck, 3).unwrap();
+        let hands = draw.hands();
+
+        assert_eq!(
+            hands[0],
+            vec![
+                Card(Rank::Seven, Suit::Clubs),
+                Card(Rank::Five, Suit::Diamonds),
+                Card(Rank::Four, Suit::Hearts),
+                Card(Rank::Three, Suit::Spades),
+                Card(Rank::Two, Suit::Diamonds),
+            ]
+        );
+        assert_eq!(
+            draw.showdown(),
+            vec![
+                vec![hands[2].clone()],
+                vec![hands[1].clone()],
+                vec![hands[0].clone()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_badugi() {
+        let mut draw = Draw::new(DrawGame::Badugi, Deck::new(0), 4).unwrap();
+
+        assert!(draw.hands().iter().all(|hand| hand.len() == 4));
+
+        for _ in 0..3 {
+            for player in 0..4 {
+                let hand = draw.hands()[player].clone();
+                assert_ok!(draw.draw(player, hand[..1].to_vec()));
+            }
+        }
+
+        assert!(draw.is_complete());
+        assert_eq!(draw.showdown().concat().len(), 4);
+    }
+}
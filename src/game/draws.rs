@@ -0,0 +1,406 @@
+use std::convert::TryInto;
+use std::ops::RangeInclusive;
+
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::card::Rank;
+use crate::card::Suit;
+use crate::combination::Combination;
+use crate::combination::Variant;
+use crate::game::Game;
+use crate::game::Hand;
+use crate::game::HandOf2;
+use crate::game::HandOf4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DrawKind {
+    FlushDraw,
+    NutFlushDraw,
+    OpenEnded,
+    DoubleGutshot,
+    Gutshot,
+    ComboDraw,
+    BackdoorFlush,
+    BackdoorStraight,
+}
+
+impl Game {
+    // Draws are only reported on the flop and the turn, and only when the
+    // hole cards take part. A nut flush draw is also a flush draw, and a
+    // combo draw is a flush draw together with any straight draw.
+    pub fn texas_holdem_draws(hand: HandOf2, board: &[Card]) -> Vec<DrawKind> {
+        Self::draws(&hand.cards(), board, 1..=2)
+    }
+
+    // Omaha hands must use exactly two hole cards and three from the board.
+    pub fn omaha_holdem_draws(hand: HandOf4, board: &[Card]) -> Vec<DrawKind> {
+        Self::draws(&hand.cards(), board, 2..=2)
+    }
+
+    fn draws(hole: &[Card], board: &[Card], used: RangeInclusive<usize>) -> Vec<DrawKind> {
+        if board.len() != 3 && board.len() != 4 {
+            return vec![];
+        }
+
+        let seen = [hole, board].concat();
+        let ranks = Combination::group_ranks(&seen);
+        let suits = Combination::group_suits(&seen);
+
+        let makes = |extra: &[Card], made: fn(Variant) -> Option<Combination>| {
+            let board = [board, extra].concat();
+
+            used.clone().any(|n| {
+                hole.iter()
+                    .copied()
+                    .combinations(n)
+                    .cartesian_product(board.iter().copied().combinations(5 - n))
+                    .any(|(hole, board)| {
+                        made(Variant([hole, board].concat().try_into().unwrap())).is_some()
+                    })
+            })
+        };
+
+        let unseen = |rank: Option<Rank>, suit: Option<Suit>| {
+            Rank::list()
+                .into_iter()
+                .rev()
+                .cartesian_product(Suit::list())
+                .map(|(rank, suit)| Card(rank, suit))
+                .filter(|card| !seen.contains(card))
+                .filter(|card| rank.map_or(true, |rank| card.0 == rank))
+                .filter(|card| suit.map_or(true, |suit| card.1 == suit))
+                .collect::<Vec<_>>()
+        };
+
+        let mut draws = vec![];
+
+        let flush_draws = if makes(&[], Combination::try_flush) {
+            vec![]
+        } else {
+            Suit::list()
+                .into_iter()
+                .filter(|suit| suits.get(suit).is_some_and(|n| *n >= 3))
+                .filter(|suit| {
+                    unseen(None, Some(*suit))
+                        .first()
+                        .is_some_and(|card| makes(&[*card], Combination::try_flush))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        if !flush_draws.is_empty() {
+            draws.push(DrawKind::FlushDraw);
+        }
+
+        // The nuts belong to whoever holds the best card of the suit that is
+        // not already on the board.
+        if flush_draws.iter().any(|suit| {
+            Rank::list()
+                .into_iter()
+                .rev()
+                .find(|rank| !board.contains(&Card(*rank, *suit)))
+                .is_some_and(|nut| hole.contains(&Card(nut, *suit)))
+        }) {
+            draws.push(DrawKind::NutFlushDraw);
+        }
+
+        let outs = if makes(&[], Combination::try_straight) {
+            vec![]
+        } else {
+            Rank::list()
+                .into_iter()
+                .filter(|rank| ranks.get(rank).map_or(true, |n| *n < 4))
+                .filter(|rank| {
+                    unseen(Some(*rank), None)
+                        .first()
+                        .is_some_and(|card| makes(&[*card], Combination::try_straight))
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let values = outs
+            .iter()
+            .flat_map(|rank| match rank {
+                Rank::Ace => vec![1, 14],
+                rank => vec![*rank as i8 + 2],
+            })
+            .collect::<Vec<_>>();
+
+        if values
+            .iter()
+            .tuple_combinations()
+            .any(|(a, b)| (a - b).abs() == 5)
+        {
+            draws.push(DrawKind::OpenEnded);
+        } else if outs.len() >= 2 {
+            draws.push(DrawKind::DoubleGutshot);
+        } else if outs.len() == 1 {
+            draws.push(DrawKind::Gutshot);
+        }
+
+        if !flush_draws.is_empty() && !outs.is_empty() {
+            draws.push(DrawKind::ComboDraw);
+        }
+
+        if board.len() == 3 {
+            let backdoor_flush = flush_draws.is_empty()
+                && !makes(&[], Combination::try_flush)
+                && Suit::list().into_iter().any(|suit| {
+                    let cards = unseen(None, Some(suit));
+
+                    cards.len() >= 2 && makes(&cards[..2], Combination::try_flush)
+                });
+
+            if backdoor_flush {
+                draws.push(DrawKind::BackdoorFlush);
+            }
+
+            let backdoor_straight = outs.is_empty()
+                && !makes(&[], Combination::try_straight)
+                && Rank::list().into_iter().tuple_combinations().any(|(a, b)| {
+                    match (unseen(Some(a), None).first(), unseen(Some(b), None).first()) {
+                        (Some(a), Some(b)) => makes(&[*a, *b], Combination::try_straight),
+                        _ => false,
+                    }
+                });
+
+            if backdoor_straight {
+                draws.push(DrawKind::BackdoorStraight);
+            }
+        }
+
+        draws
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::game::DrawKind;
+    use crate::game::Game;
+    use crate::game::HandOf2;
+    use crate::game::HandOf4;
+
+    #[test]
+    fn test_flush_draws() {
+        let board = [
+            Card(Rank::Ace, Suit::Hearts),
+            Card(Rank::Seven, Suit::Hearts),
+            Card(Rank::Two, Suit::Spades),
+        ];
+
+        assert_eq!(
+            Game::texas_holdem_draws(
+                HandOf2::new([
+                    Card(Rank::King, Suit::Hearts),
+                    Card(Rank::Queen, Suit::Hearts)
+                ]),
+                &board
+            ),
+            vec![
+                DrawKind::FlushDraw,
+                DrawKind::NutFlushDraw,
+                DrawKind::BackdoorStraight
+            ]
+        );
+        assert_eq!(
+            Game::texas_holdem_draws(
+                HandOf2::new([
+                    Card(Rank::Nine, Suit::Hearts),
+                    Card(Rank::Three, Suit::Hearts)
+                ]),
+                &board
+            ),
+            vec![DrawKind::FlushDraw, DrawKind::BackdoorStraight]
+        );
+        assert_eq!(
+            Game::texas_holdem_draws(
+                HandOf2::new([
+                    Card(Rank::Nine, Suit::Hearts),
+                    Card(Rank::Jack, Suit::Clubs)
+                ]),
+                &board
+            ),
+            vec![DrawKind::BackdoorFlush, DrawKind::BackdoorStraight]
+        );
+    }
+
+    #[test]
+    fn test_straight_draws() {
+        let board = [
+            Card(Rank::Six, Suit::Clubs),
+            Card(Rank::Seven, Suit::Spades),
+            Card(Rank::King, Suit::Diamonds),
+        ];
+
+        assert_eq!(
+            Game::texas_holdem_draws(
+                HandOf2::new([
+                    Card(Rank::Eight, Suit::Hearts),
+                    Card(Rank::Nine, Suit::Diamonds)
+                ]),
+                &board
+            ),
+            vec![DrawKind::OpenEnded]
+        );
+        assert_eq!(
+            Game::texas_holdem_draws(
+                HandOf2::new([
+                    Card(Rank::Eight, Suit::Hearts),
+                    Card(Rank::Ten, Suit::Diamonds)
+                ]),
+                &board
+            ),
+            vec![DrawKind::Gutshot]
+        );
+        assert_eq!(
+            Game::texas_holdem_draws(
+                HandOf2::new([
+                    Card(Rank::Five, Suit::Hearts),
+                    Card(Rank::Nine, Suit::Diamonds)
+                ]),
+                &[
+                    Card(Rank::Seven, Suit::Clubs),
+                    Card(Rank::Eight, Suit::Spades),
+                    Card(Rank::Jack, Suit::Diamonds),
+                ]
+            ),
+            vec![DrawKind::DoubleGutshot]
+        );
+        assert_eq!(
+            Game::texas_holdem_draws(
+                HandOf2::new([
+                    Card(Rank::Ace, Suit::Hearts),
+                    Card(Rank::Two, Suit::Diamonds)
+                ]),
+                &[
+                    Card(Rank::Three, Suit::Clubs),
+                    Card(Rank::Nine, Suit::Spades),
+                    Card(Rank::King, Suit::Diamonds),
+                ]
+            ),
+            vec![DrawKind::BackdoorStraight]
+        );
+    }
+
+    #[test]
+    fn test_combo_draw() {
+        assert_eq!(
+            Game::texas_holdem_draws(
+                HandOf2::new([
+                    Card(Rank::Eight, Suit::Clubs),
+                    Card(Rank::Nine, Suit::Clubs)
+                ]),
+                &[
+                    Card(Rank::Six, Suit::Clubs),
+                    Card(Rank::Seven, Suit::Spades),
+                    Card(Rank::King, Suit::Clubs),
+                    Card(Rank::Two, Suit::Hearts),
+                ]
+            ),
+            vec![
+                DrawKind::FlushDraw,
+                DrawKind::OpenEnded,
+                DrawKind::ComboDraw
+            ]
+        );
+    }
+
+    #[test]
+    fn test_board_draws_do_not_count() {
+        assert_eq!(
+            Game::texas_holdem_draws(
+                HandOf2::new([Card(Rank::Ace, Suit::Clubs), Card(Rank::Two, Suit::Spades)]),
+                &[
+                    Card(Rank::Five, Suit::Hearts),
+                    Card(Rank::Six, Suit::Hearts),
+                    Card(Rank::Seven, Suit::Hearts),
+                    Card(Rank::Eight, Suit::Hearts),
+                ]
+            ),
+            vec![]
+        );
+    }
+
+    #[test]
+    fn test_omaha_draws() {
+        let board = [
+            Card(Rank::King, Suit::Hearts),
+            Card(Rank::Queen, Suit::Hearts),
+            Card(Rank::Seven, Suit::Spades),
+        ];
+
+        assert_eq!(
+            Game::omaha_holdem_draws(
+                HandOf4::new([
+                    Card(Rank::Ace, Suit::Hearts),
+                    Card(Rank::Two, Suit::Hearts),
+                    Card(Rank::Three, Suit::Clubs),
+                    Card(Rank::Four, Suit::Diamonds),
+                ]),
+                &board
+            ),
+            vec![
+                DrawKind::FlushDraw,
+                DrawKind::NutFlushDraw,
+                DrawKind::BackdoorStraight
+            ]
+        );
+
+        // One heart in hand is a flush draw in Hold'em but not in Omaha.
+        let board = [
+            Card(Rank::King, Suit::Hearts),
+            Card(Rank::Queen, Suit::Hearts),
+            Card(Rank::Jack, Suit::Hearts),
+        ];
+
+        assert!(!Game::omaha_holdem_draws(
+            HandOf4::new([
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::Two, Suit::Clubs),
+                Card(Rank::Three, Suit::Clubs),
+                Card(Rank::Four, Suit::Diamonds),
+            ]),
+            &board
+        )
+        .contains(&DrawKind::FlushDraw));
+        assert!(Game::texas_holdem_draws(
+            HandOf2::new([Card(Rank::Ace, Suit::Hearts), Card(Rank::Two, Suit::Clubs)]),
+            &board
+        )
+        .contains(&DrawKind::FlushDraw));
+
+        // A single connecting hole card is not enough in Omaha.
+        let board = [
+            Card(Rank::Six, Suit::Clubs),
+            Card(Rank::Seven, Suit::Spades),
+            Card(Rank::King, Suit::Diamonds),
+        ];
+
+        assert!(!Game::omaha_holdem_draws(
+            HandOf4::new([
+                Card(Rank::Eight, Suit::Hearts),
+                Card(Rank::King, Suit::Clubs),
+                Card(Rank::King, Suit::Spades),
+                Card(Rank::Two, Suit::Diamonds),
+            ]),
+            &board
+        )
+        .contains(&DrawKind::OpenEnded));
+        assert_eq!(
+            Game::omaha_holdem_draws(
+                HandOf4::new([
+                    Card(Rank::Eight, Suit::Hearts),
+                    Card(Rank::Nine, Suit::Clubs),
+                    Card(Rank::King, Suit::Spades),
+                    Card(Rank::Two, Suit::Diamonds),
+                ]),
+                &board
+            ),
+            vec![DrawKind::OpenEnded]
+        );
+    }
+}
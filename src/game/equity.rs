@@ -0,0 +1,361 @@
+use std::io::Error;
+use std::io::ErrorKind;
+
+use ::itertools::Itertools;
+use ::rand::seq::SliceRandom;
+use ::rand::SeedableRng;
+use ::rand_chacha::ChaCha8Rng;
+
+use crate::card::Card;
+use crate::card::Deck;
+use crate::combination::Combination;
+use crate::game::Game;
+use crate::game::Hand;
+use crate::game::HandOf2;
+use crate::game::HandOf3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Runouts {
+    Exhaustive,
+    Sampled { samples: usize, seed: u64 },
+}
+
+impl Game {
+    pub fn texas_holdem_equity(
+        hands: Vec<HandOf2>,
+        board: Vec<Card>,
+        dead: Vec<Card>,
+        runouts: Runouts,
+    ) -> Result<Vec<f64>, Error> {
+        if hands.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "no hands"));
+        }
+
+        if board.len() > 5 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "board has more than five cards",
+            ));
+        }
+
+        if let Runouts::Sampled { samples: 0, .. } = runouts {
+            return Err(Error::new(ErrorKind::InvalidInput, "no samples"));
+        }
+
+        let known = hands
+            .iter()
+            .flat_map(|hand| hand.cards())
+            .chain(board.iter().copied())
+            .chain(dead.iter().copied())
+            .collect::<Vec<_>>();
+
+        if known.iter().unique().count() != known.len() {
+            return Err(Error::new(ErrorKind::InvalidInput, "duplicate cards"));
+        }
+
+        let stub = Deck::list()
+            .into_iter()
+            .filter(|card| !known.contains(card))
+            .collect::<Vec<_>>();
+
+        let missing = 5 - board.len();
+
+        let runouts: Box<dyn Iterator<Item = Vec<Card>>> = match runouts {
+            Runouts::Exhaustive => Box::new(stub.iter().copied().combinations(missing)),
+            Runouts::Sampled { samples, seed } => {
+                let mut rng = ChaCha8Rng::seed_from_u64(seed);
+
+                Box::new((0..samples).map(move |_| {
+                    stub.choose_multiple(&mut rng, missing)
+                        .copied()
+                        .collect::<Vec<_>>()
+                }))
+            }
+        };
+
+        let (wins, count) =
+            runouts.fold((vec![0.0; hands.len()], 0), |(mut wins, count), runout| {
+                let board = [board.clone(), runout].concat();
+
+                // Full-precision strengths, so only real ties split.
+                let combinations = hands
+                    .iter()
+                    .map(|hand| Combination::strength(&[&hand.cards()[..], &board[..]].concat()))
+                    .collect::<Vec<_>>();
+
+                let best = combinations.iter().max().unwrap();
+                let winners = combinations.iter().filter(|comb| *comb == best).count();
+
+                combinations
+                    .iter()
+                    .zip(wins.iter_mut())
+                    .filter(|(comb, _)| *comb == best)
+                    .for_each(|(_, win)| *win += 1.0 / winners as f64);
+
+                (wins, count + 1)
+            });
+
+        Ok(wins.into_iter().map(|win| win / count as f64).collect())
+    }
+
+    pub fn pineapple_discard_equity(
+        hand: HandOf3,
+        opponents: Vec<HandOf2>,
+        board: Vec<Card>,
+        runouts: Runouts,
+    ) -> Result<Vec<(Card, f64)>, Error> {
+        hand.cards()
+            .into_iter()
+            .map(|discard| {
+                let hands = [vec![hand.discard(discard).unwrap()], opponents.clone()].concat();
+                let equity =
+                    Self::texas_holdem_equity(hands, board.clone(), vec![discard], runouts)?;
+
+                Ok((discard, equity[0]))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use super::Runouts;
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::game::Game;
+    use crate::game::HandOf2;
+    use crate::game::HandOf3;
+
+    #[test]
+    fn test_texas_holdem_equity_river() {
+        let equity = Game::texas_holdem_equity(
+            vec![
+                HandOf2::new([Card(Rank::Ace, Suit::Spades), Card(Rank::Ace, Suit::Hearts)]),
+                HandOf2::new([
+                    Card(Rank::King, Suit::Spades),
+                    Card(Rank::King, Suit::Hearts),
+                ]),
+            ],
+            vec![
+                Card(Rank::Two, Suit::Clubs),
+                Card(Rank::Seven, Suit::Diamonds),
+                Card(Rank::Nine, Suit::Hearts),
+                Card(Rank::Jack, Suit::Spades),
+                Card(Rank::Four, Suit::Clubs),
+            ],
+            vec![],
+            Runouts::Exhaustive,
+        )
+        .unwrap();
+
+        assert_eq!(equity, vec![1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_texas_holdem_equity_turn() {
+        let equity = Game::texas_holdem_equity(
+            vec![
+                HandOf2::new([Card(Rank::Ace, Suit::Spades), Card(Rank::Ace, Suit::Hearts)]),
+                HandOf2::new([
+                    Card(Rank::King, Suit::Spades),
+                    Card(Rank::King, Suit::Hearts),
+                ]),
+            ],
+            vec![
+                Card(Rank::Two, Suit::Clubs),
+                Card(Rank::Seven, Suit::Diamonds),
+                Card(Rank::Nine, Suit::Hearts),
+                Card(Rank::Jack, Suit::Clubs),
+            ],
+            vec![],
+            Runouts::Exhaustive,
+        )
+        .unwrap();
+
+        assert_eq!(equity, vec![42.0 / 44.0, 2.0 / 44.0]);
+    }
+
+    #[test]
+    fn test_texas_holdem_equity_split() {
+        let equity = Game::texas_holdem_equity(
+            vec![
+                HandOf2::new([
+                    Card(Rank::Two, Suit::Spades),
+                    Card(Rank::Three, Suit::Hearts),
+                ]),
+                HandOf2::new([
+                    Card(Rank::Two, Suit::Clubs),
+                    Card(Rank::Three, Suit::Diamonds),
+                ]),
+            ],
+            vec![
+                Card(Rank::Ace, Suit::Clubs),
+                Card(Rank::King, Suit::Diamonds),
+                Card(Rank::Queen, Suit::Hearts),
+                Card(Rank::Jack, Suit::Clubs),
+                Card(Rank::Ten, Suit::Spades),
+            ],
+            vec![],
+            Runouts::Exhaustive,
+        )
+        .unwrap();
+
+        assert_eq!(equity, vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn test_texas_holdem_equity_kickers() {
+        let equity = Game::texas_holdem_equity(
+            vec![
+                HandOf2::new([
+                    Card(Rank::Ace, Suit::Spades),
+                    Card(Rank::King, Suit::Hearts),
+                ]),
+                HandOf2::new([
+                    Card(Rank::Ace, Suit::Clubs),
+                    Card(Rank::Queen, Suit::Diamonds),
+                ]),
+            ],
+            vec![
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::Seven, Suit::Diamonds),
+                Card(Rank::Two, Suit::Clubs),
+                Card(Rank::Four, Suit::Spades),
+                Card(Rank::Nine, Suit::Clubs),
+            ],
+            vec![],
+            Runouts::Exhaustive,
+        )
+        .unwrap();
+
+        assert_eq!(equity, vec![1.0, 0.0]);
+
+        let equity = Game::texas_holdem_equity(
+            vec![
+                HandOf2::new([
+                    Card(Rank::Eight, Suit::Hearts),
+                    Card(Rank::Three, Suit::Clubs),
+                ]),
+                HandOf2::new([
+                    Card(Rank::Seven, Suit::Hearts),
+                    Card(Rank::Three, Suit::Diamonds),
+                ]),
+            ],
+            vec![
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::King, Suit::Hearts),
+                Card(Rank::Nine, Suit::Hearts),
+                Card(Rank::Six, Suit::Hearts),
+                Card(Rank::Two, Suit::Clubs),
+            ],
+            vec![],
+            Runouts::Exhaustive,
+        )
+        .unwrap();
+
+        assert_eq!(equity, vec![1.0, 0.0]);
+    }
+
+    #[test]
+    fn test_texas_holdem_equity_sampled() {
+        let hands = vec![
+            HandOf2::new([Card(Rank::Ace, Suit::Spades), Card(Rank::Ace, Suit::Hearts)]),
+            HandOf2::new([
+                Card(Rank::Seven, Suit::Clubs),
+                Card(Rank::Two, Suit::Diamonds),
+            ]),
+        ];
+
+        let runouts = Runouts::Sampled {
+            samples: 500,
+            seed: 0,
+        };
+
+        let equity = Game::texas_holdem_equity(hands.clone(), vec![], vec![], runouts).unwrap();
+
+        assert_gt!(equity[0], 0.8);
+        assert_eq!(
+            equity,
+            Game::texas_holdem_equity(hands, vec![], vec![], runouts).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_pineapple_discard_equity() {
+        let equity = Game::pineapple_discard_equity(
+            HandOf3::new([
+                Card(Rank::Ace, Suit::Spades),
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::Two, Suit::Clubs),
+            ]),
+            vec![HandOf2::new([
+                Card(Rank::King, Suit::Spades),
+                Card(Rank::King, Suit::Hearts),
+            ])],
+            vec![
+                Card(Rank::Nine, Suit::Clubs),
+                Card(Rank::Seven, Suit::Diamonds),
+                Card(Rank::Four, Suit::Hearts),
+            ],
+            Runouts::Exhaustive,
+        )
+        .unwrap();
+
+        let (best, _) = equity
+            .into_iter()
+            .max_by(|(_, equity_a), (_, equity_b)| equity_a.partial_cmp(equity_b).unwrap())
+            .unwrap();
+
+        assert_eq!(best, Card(Rank::Two, Suit::Clubs));
+    }
+
+    #[test]
+    fn test_texas_holdem_equity_invalid() {
+        let hands = vec![
+            HandOf2::new([Card(Rank::Ace, Suit::Spades), Card(Rank::Ace, Suit::Hearts)]),
+            HandOf2::new([
+                Card(Rank::King, Suit::Spades),
+                Card(Rank::King, Suit::Hearts),
+            ]),
+        ];
+        let board = vec![
+            Card(Rank::Two, Suit::Clubs),
+            Card(Rank::Seven, Suit::Diamonds),
+            Card(Rank::Nine, Suit::Hearts),
+            Card(Rank::Jack, Suit::Spades),
+            Card(Rank::Four, Suit::Clubs),
+            Card(Rank::Five, Suit::Clubs),
+        ];
+
+        assert_err!(Game::texas_holdem_equity(
+            hands.clone(),
+            board,
+            vec![],
+            Runouts::Exhaustive
+        ));
+        assert_err!(Game::texas_holdem_equity(
+            hands.clone(),
+            vec![Card(Rank::Ace, Suit::Spades)],
+            vec![],
+            Runouts::Exhaustive
+        ));
+        assert_err!(Game::texas_holdem_equity(
+            hands,
+            vec![],
+            vec![],
+            Runouts::Sampled {
+                samples: 0,
+                seed: 0
+            }
+        ));
+        assert_err!(Game::texas_holdem_equity(
+            vec![],
+            vec![],
+            vec![],
+            Runouts::Exhaustive
+        ));
+    }
+}
@@ -30,6 +30,44 @@ impl TryFrom<Vec<Card>> for HandOf2 {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandOf3([Card; 3]);
+
+impl HandOf3 {
+    pub fn new(cards: [Card; 3]) -> Self {
+        Self(cards)
+    }
+
+    pub fn discard(&self, card: Card) -> Option<HandOf2> {
+        if self.0.contains(&card) {
+            let cards = self
+                .0
+                .iter()
+                .copied()
+                .filter(|c| *c != card)
+                .collect::<Vec<_>>();
+
+            Some(HandOf2(cards.try_into().unwrap()))
+        } else {
+            None
+        }
+    }
+}
+
+impl Hand for HandOf3 {
+    fn cards(&self) -> Vec<Card> {
+        self.0.to_vec()
+    }
+}
+
+impl TryFrom<Vec<Card>> for HandOf3 {
+    type Error = std::io::Error;
+
+    fn try_from(value: Vec<Card>) -> Result<Self, Self::Error> {
+        Ok(Self(value.try_into().unwrap()))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct HandOf4([Card; 4]);
 
@@ -76,11 +114,34 @@ impl TryFrom<Vec<Card>> for HandOf5 {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HandOf7([Card; 7]);
+
+impl HandOf7 {
+    pub fn new(cards: [Card; 7]) -> Self {
+        Self(cards)
+    }
+}
+
+impl Hand for HandOf7 {
+    fn cards(&self) -> Vec<Card> {
+        self.0.to_vec()
+    }
+}
+
+impl TryFrom<Vec<Card>> for HandOf7 {
+    type Error = std::io::Error;
+
+    fn try_from(value: Vec<Card>) -> Result<Self, Self::Error> {
+        Ok(Self(value.try_into().unwrap()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::convert::TryFrom;
 
-    use super::{HandOf2, HandOf4, HandOf5};
+    use super::{HandOf2, HandOf3, HandOf4, HandOf5, HandOf7};
     use crate::card::{Card, Rank, Suit};
 
     #[test]
@@ -93,6 +154,35 @@ mod tests {
         assert_eq!(HandOf2::try_from(cards.to_vec()).unwrap(), HandOf2(cards),);
     }
 
+    #[test]
+    fn test_hand_of_3_from_vec() {
+        let cards = [
+            Card(Rank::Ace, Suit::Diamonds),
+            Card(Rank::Ace, Suit::Clubs),
+            Card(Rank::Ace, Suit::Hearts),
+        ];
+
+        assert_eq!(HandOf3::try_from(cards.to_vec()).unwrap(), HandOf3(cards),);
+    }
+
+    #[test]
+    fn test_hand_of_3_discard() {
+        let hand = HandOf3([
+            Card(Rank::Ace, Suit::Diamonds),
+            Card(Rank::Ten, Suit::Clubs),
+            Card(Rank::Two, Suit::Hearts),
+        ]);
+
+        assert_eq!(
+            hand.discard(Card(Rank::Ten, Suit::Clubs)),
+            Some(HandOf2([
+                Card(Rank::Ace, Suit::Diamonds),
+                Card(Rank::Two, Suit::Hearts),
+            ]))
+        );
+        assert_eq!(hand.discard(Card(Rank::Ten, Suit::Hearts)), None);
+    }
+
     #[test]
     fn test_hand_of_4_from_vec() {
         let cards = [
@@ -117,4 +207,19 @@ mod tests {
 
         assert_eq!(HandOf5::try_from(cards.to_vec()).unwrap(), HandOf5(cards),);
     }
+
+    #[test]
+    fn test_hand_of_7_from_vec() {
+        let cards = [
+            Card(Rank::Ace, Suit::Diamonds),
+            Card(Rank::Ace, Suit::Clubs),
+            Card(Rank::Ace, Suit::Hearts),
+            Card(Rank::Ace, Suit::Spades),
+            Card(Rank::King, Suit::Diamonds),
+            Card(Rank::King, Suit::Clubs),
+            Card(Rank::King, Suit::Hearts),
+        ];
+
+        assert_eq!(HandOf7::try_from(cards.to_vec()).unwrap(), HandOf7(cards),);
+    }
 }
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::convert::TryInto;
+use std::hash::Hash;
 
 use ::itertools::Itertools;
 
@@ -12,6 +13,22 @@ use crate::game::Game;
 use crate::game::Hand;
 use crate::game::HandOf2;
 use crate::game::HandOf4;
+use crate::game::HandOf7;
+use crate::game::HiLo;
+
+impl HiLo {
+    pub fn hand(&self) -> Vec<Card> {
+        self.hand.clone()
+    }
+
+    pub fn high(&self) -> (Combination, Variant) {
+        self.high
+    }
+
+    pub fn low(&self) -> Option<(Combination, Variant)> {
+        self.low
+    }
+}
 
 impl Game {
     pub fn rank_hands(game: Self) -> Vec<(Vec<Card>, Combination, Variant)> {
@@ -31,12 +48,52 @@ impl Game {
                 .map(|hand| (hand, Variant::try_from(hand.cards()).unwrap()))
                 .map(|(hand, variant)| (hand.cards(), Combination::from_variant(variant), variant))
                 .collect::<Vec<_>>(),
+            Self::Razz(hands) => hands
+                .into_iter()
+                .map(|hand| (hand.cards(), Self::razz_combination(hand)))
+                .map(|(hand, (combination, variant))| (hand, combination, variant))
+                .collect::<Vec<_>>(),
+            Self::StudHiLo(hands) => hands
+                .into_iter()
+                .map(|hand| (hand.cards(), Self::stud_combination(hand)))
+                .map(|(hand, (combination, variant))| (hand, combination, variant))
+                .collect::<Vec<_>>(),
+            Self::DeuceToSevenDraw(hands) => hands
+                .iter()
+                .map(|hand| (hand, Variant::try_from(hand.cards()).unwrap()))
+                .map(|(hand, variant)| {
+                    (
+                        hand.cards(),
+                        Combination::from_variant_deuce_to_seven(variant),
+                        variant,
+                    )
+                })
+                .collect::<Vec<_>>(),
         }
     }
 
-    pub fn group_hands(
-        hands: Vec<(Vec<Card>, Combination)>,
-    ) -> HashMap<Combination, Vec<Vec<Card>>> {
+    pub fn rank_hi_lo_hands(game: Self) -> Vec<HiLo> {
+        match game {
+            Self::StudHiLo(hands) => hands
+                .into_iter()
+                .map(|hand| HiLo {
+                    hand: hand.cards(),
+                    high: Self::stud_combination(hand),
+                    low: Self::eight_or_better_combination(hand),
+                })
+                .collect::<Vec<_>>(),
+            game => Self::rank_hands(game)
+                .into_iter()
+                .map(|(hand, combination, variant)| HiLo {
+                    hand,
+                    high: (combination, variant),
+                    low: None,
+                })
+                .collect::<Vec<_>>(),
+        }
+    }
+
+    pub fn group_hands<C: Hash + Eq>(hands: Vec<(Vec<Card>, C)>) -> HashMap<C, Vec<Vec<Card>>> {
         hands
             .into_iter()
             .fold(HashMap::new(), |mut acc, (hand, comb)| {
@@ -54,7 +111,7 @@ impl Game {
             })
     }
 
-    pub fn sort_hands(grouped_hands: HashMap<Combination, Vec<Vec<Card>>>) -> Vec<Vec<Vec<Card>>> {
+    pub fn sort_hands<C: Ord>(grouped_hands: HashMap<C, Vec<Vec<Card>>>) -> Vec<Vec<Vec<Card>>> {
         grouped_hands
             .into_iter()
             .sorted_by(|(comb_a, _), (comb_b, _)| comb_a.cmp(comb_b))
@@ -62,7 +119,9 @@ impl Game {
             .collect()
     }
 
-    fn texas_holdem_combination(board: Board, hand: HandOf2) -> (Combination, Variant) {
+    // The five cards reported for Hold'em and Omaha are the best ones on
+    // every kicker, so their strength settles ties that `Combination` cannot.
+    pub(crate) fn texas_holdem_combination(board: Board, hand: HandOf2) -> (Combination, Variant) {
         board
             .cards()
             .iter()
@@ -70,27 +129,54 @@ impl Game {
             .copied()
             .combinations(5)
             .map(|comb| Variant(comb.try_into().unwrap()))
+            .max_by_key(|variant| Combination::strength(&variant.0))
             .map(|variant| (Combination::from_variant(variant), variant))
-            .max_by(|(comb_a, _), (comb_b, _)| comb_a.cmp(comb_b))
             .unwrap()
     }
 
-    fn omaha_holdem_combination(board: Board, hand: HandOf4) -> (Combination, Variant) {
+    pub(crate) fn omaha_holdem_combination(board: Board, hand: HandOf4) -> (Combination, Variant) {
         hand.cards()
             .iter()
             .combinations(2)
             .cartesian_product(board.cards().iter().combinations(3))
-            .map(|(h, b)| {
-                h.into_iter()
-                    .chain(b.into_iter())
-                    .copied()
-                    .collect::<Vec<_>>()
-            })
+            .map(|(h, b)| h.into_iter().chain(b).copied().collect::<Vec<_>>())
             .map(|cards| Variant(cards.try_into().unwrap()))
+            .max_by_key(|variant| Combination::strength(&variant.0))
             .map(|variant| (Combination::from_variant(variant), variant))
+            .unwrap()
+    }
+
+    fn razz_combination(hand: HandOf7) -> (Combination, Variant) {
+        hand.cards()
+            .into_iter()
+            .combinations(5)
+            .map(|comb| Variant(comb.try_into().unwrap()))
+            .map(|variant| (Combination::from_variant_ace_to_five(variant), variant))
             .max_by(|(comb_a, _), (comb_b, _)| comb_a.cmp(comb_b))
             .unwrap()
     }
+
+    // Picked by full strength so that the reported five cards carry the best
+    // kickers, not just the first subset with the best `Combination`.
+    fn stud_combination(hand: HandOf7) -> (Combination, Variant) {
+        hand.cards()
+            .into_iter()
+            .combinations(5)
+            .map(|comb| Variant(comb.try_into().unwrap()))
+            .max_by_key(|variant| Combination::strength(&variant.0))
+            .map(|variant| (Combination::from_variant(variant), variant))
+            .unwrap()
+    }
+
+    fn eight_or_better_combination(hand: HandOf7) -> Option<(Combination, Variant)> {
+        hand.cards()
+            .into_iter()
+            .combinations(5)
+            .map(|comb| Variant(comb.try_into().unwrap()))
+            .map(|variant| (Combination::from_variant_ace_to_five(variant), variant))
+            .filter(|(comb, _)| comb.is_eight_or_better())
+            .max_by(|(comb_a, _), (comb_b, _)| comb_a.cmp(comb_b))
+    }
 }
 
 #[cfg(test)]
@@ -107,6 +193,8 @@ mod tests {
     use crate::game::HandOf2;
     use crate::game::HandOf4;
     use crate::game::HandOf5;
+    use crate::game::HandOf7;
+    use crate::game::HiLo;
 
     #[test]
     fn test_texas_holdem_ordering() {
@@ -172,8 +260,8 @@ mod tests {
                         Card(Rank::Queen, Suit::Spades),
                         Card(Rank::King, Suit::Diamonds),
                         Card(Rank::King, Suit::Spades),
-                        Card(Rank::King, Suit::Hearts),
-                        Card(Rank::Two, Suit::Clubs)
+                        Card(Rank::Jack, Suit::Diamonds),
+                        Card(Rank::King, Suit::Hearts)
                     ])
                 ),
                 (
@@ -265,7 +353,7 @@ mod tests {
                     },
                     Variant([
                         Card(Rank::King, Suit::Hearts),
-                        Card(Rank::Two, Suit::Diamonds),
+                        Card(Rank::Eight, Suit::Clubs),
                         Card(Rank::Queen, Suit::Spades),
                         Card(Rank::King, Suit::Diamonds),
                         Card(Rank::King, Suit::Spades)
@@ -409,6 +497,209 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_razz_ordering() {
+        let game = Game::Razz(vec![
+            HandOf7::new([
+                Card(Rank::King, Suit::Hearts),
+                Card(Rank::Two, Suit::Clubs),
+                Card(Rank::Eight, Suit::Clubs),
+                Card(Rank::Two, Suit::Diamonds),
+                Card(Rank::Five, Suit::Hearts),
+                Card(Rank::Six, Suit::Hearts),
+                Card(Rank::Four, Suit::Spades),
+            ]),
+            HandOf7::new([
+                Card(Rank::Ace, Suit::Clubs),
+                Card(Rank::Two, Suit::Hearts),
+                Card(Rank::Three, Suit::Hearts),
+                Card(Rank::Four, Suit::Hearts),
+                Card(Rank::Five, Suit::Hearts),
+                Card(Rank::King, Suit::Clubs),
+                Card(Rank::King, Suit::Spades),
+            ]),
+            HandOf7::new([
+                Card(Rank::Queen, Suit::Diamonds),
+                Card(Rank::Queen, Suit::Hearts),
+                Card(Rank::Jack, Suit::Clubs),
+                Card(Rank::Jack, Suit::Diamonds),
+                Card(Rank::Nine, Suit::Diamonds),
+                Card(Rank::Nine, Suit::Clubs),
+                Card(Rank::Ten, Suit::Spades),
+            ]),
+        ]);
+
+        let ranked = Game::rank_hands(game)
+            .into_iter()
+            .sorted_by(
+                |(_hand_a, comb_a, _variant_a), (_hand_b, comb_b, _variant_b)| comb_a.cmp(comb_b),
+            )
+            .map(|(_hand, comb, _variant)| comb)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            ranked,
+            vec![
+                Combination::AceToFive {
+                    ranks: [Rank::Nine, Rank::Nine, Rank::Queen, Rank::Jack, Rank::Ten]
+                },
+                Combination::AceToFive {
+                    ranks: [Rank::Eight, Rank::Six, Rank::Five, Rank::Four, Rank::Two]
+                },
+                Combination::AceToFive {
+                    ranks: [Rank::Five, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stud_hi_lo_ranking() {
+        let game = Game::StudHiLo(vec![
+            HandOf7::new([
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::Two, Suit::Hearts),
+                Card(Rank::Three, Suit::Clubs),
+                Card(Rank::Four, Suit::Hearts),
+                Card(Rank::Eight, Suit::Hearts),
+                Card(Rank::King, Suit::Hearts),
+                Card(Rank::King, Suit::Spades),
+            ]),
+            HandOf7::new([
+                Card(Rank::Queen, Suit::Diamonds),
+                Card(Rank::Queen, Suit::Hearts),
+                Card(Rank::Queen, Suit::Clubs),
+                Card(Rank::Jack, Suit::Diamonds),
+                Card(Rank::Nine, Suit::Diamonds),
+                Card(Rank::Two, Suit::Clubs),
+                Card(Rank::Three, Suit::Spades),
+            ]),
+        ]);
+
+        assert_eq!(
+            Game::rank_hi_lo_hands(game),
+            vec![
+                HiLo {
+                    hand: vec![
+                        Card(Rank::Ace, Suit::Hearts),
+                        Card(Rank::Two, Suit::Hearts),
+                        Card(Rank::Three, Suit::Clubs),
+                        Card(Rank::Four, Suit::Hearts),
+                        Card(Rank::Eight, Suit::Hearts),
+                        Card(Rank::King, Suit::Hearts),
+                        Card(Rank::King, Suit::Spades),
+                    ],
+                    high: (
+                        Combination::Flush { rank: Rank::Ace },
+                        Variant([
+                            Card(Rank::Ace, Suit::Hearts),
+                            Card(Rank::Two, Suit::Hearts),
+                            Card(Rank::Four, Suit::Hearts),
+                            Card(Rank::Eight, Suit::Hearts),
+                            Card(Rank::King, Suit::Hearts),
+                        ])
+                    ),
+                    low: Some((
+                        Combination::AceToFive {
+                            ranks: [Rank::Eight, Rank::Four, Rank::Three, Rank::Two, Rank::Ace]
+                        },
+                        Variant([
+                            Card(Rank::Ace, Suit::Hearts),
+                            Card(Rank::Two, Suit::Hearts),
+                            Card(Rank::Three, Suit::Clubs),
+                            Card(Rank::Four, Suit::Hearts),
+                            Card(Rank::Eight, Suit::Hearts),
+                        ])
+                    ))
+                },
+                HiLo {
+                    hand: vec![
+                        Card(Rank::Queen, Suit::Diamonds),
+                        Card(Rank::Queen, Suit::Hearts),
+                        Card(Rank::Queen, Suit::Clubs),
+                        Card(Rank::Jack, Suit::Diamonds),
+                        Card(Rank::Nine, Suit::Diamonds),
+                        Card(Rank::Two, Suit::Clubs),
+                        Card(Rank::Three, Suit::Spades),
+                    ],
+                    high: (
+                        Combination::ThreeOfAKind {
+                            rank: Rank::Queen,
+                            kicker: Rank::Jack
+                        },
+                        Variant([
+                            Card(Rank::Queen, Suit::Diamonds),
+                            Card(Rank::Queen, Suit::Hearts),
+                            Card(Rank::Queen, Suit::Clubs),
+                            Card(Rank::Jack, Suit::Diamonds),
+                            Card(Rank::Nine, Suit::Diamonds),
+                        ])
+                    ),
+                    low: None
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deuce_to_seven_draw_ordering() {
+        let game = Game::DeuceToSevenDraw(vec![
+            HandOf5::new([
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::Two, Suit::Clubs),
+                Card(Rank::Three, Suit::Clubs),
+                Card(Rank::Four, Suit::Diamonds),
+                Card(Rank::Five, Suit::Hearts),
+            ]),
+            HandOf5::new([
+                Card(Rank::Seven, Suit::Clubs),
+                Card(Rank::Five, Suit::Diamonds),
+                Card(Rank::Four, Suit::Hearts),
+                Card(Rank::Three, Suit::Spades),
+                Card(Rank::Two, Suit::Clubs),
+            ]),
+            HandOf5::new([
+                Card(Rank::Six, Suit::Diamonds),
+                Card(Rank::Five, Suit::Clubs),
+                Card(Rank::Four, Suit::Clubs),
+                Card(Rank::Three, Suit::Diamonds),
+                Card(Rank::Two, Suit::Diamonds),
+            ]),
+        ]);
+
+        let hands = Game::rank_hands(game)
+            .into_iter()
+            .map(|(hand, comb, _variant)| (hand, comb))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            Game::sort_hands(Game::group_hands(hands)),
+            vec![
+                vec![vec![
+                    Card(Rank::Six, Suit::Diamonds),
+                    Card(Rank::Five, Suit::Clubs),
+                    Card(Rank::Four, Suit::Clubs),
+                    Card(Rank::Three, Suit::Diamonds),
+                    Card(Rank::Two, Suit::Diamonds),
+                ]],
+                vec![vec![
+                    Card(Rank::Ace, Suit::Hearts),
+                    Card(Rank::Two, Suit::Clubs),
+                    Card(Rank::Three, Suit::Clubs),
+                    Card(Rank::Four, Suit::Diamonds),
+                    Card(Rank::Five, Suit::Hearts),
+                ]],
+                vec![vec![
+                    Card(Rank::Seven, Suit::Clubs),
+                    Card(Rank::Five, Suit::Diamonds),
+                    Card(Rank::Four, Suit::Hearts),
+                    Card(Rank::Three, Suit::Spades),
+                    Card(Rank::Two, Suit::Clubs),
+                ]],
+            ]
+        );
+    }
+
     #[test]
     fn test_group_hands() {
         let hands = vec![
@@ -0,0 +1,87 @@
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::combination::Combination;
+use crate::game::Game;
+
+impl Game {
+    pub fn razz_bring_in(door_cards: Vec<Card>) -> Option<usize> {
+        door_cards
+            .into_iter()
+            .enumerate()
+            .max_by(|(_, card_a), (_, card_b)| {
+                Combination::ace_low(card_a.rank())
+                    .cmp(&Combination::ace_low(card_b.rank()))
+                    .then(card_a.suit().precedence().cmp(&card_b.suit().precedence()))
+            })
+            .map(|(seat, _)| seat)
+    }
+
+    pub fn razz_first_to_act(up_cards: Vec<Vec<Card>>) -> Option<usize> {
+        up_cards
+            .into_iter()
+            .map(|cards| cards.into_iter().map(|card| card.rank()).collect_vec())
+            .enumerate()
+            .min_by(|(_, ranks_a), (_, ranks_b)| {
+                Combination::ace_to_five_key(ranks_a).cmp(&Combination::ace_to_five_key(ranks_b))
+            })
+            .map(|(seat, _)| seat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::game::Game;
+
+    #[test]
+    fn test_razz_bring_in() {
+        assert_eq!(
+            Game::razz_bring_in(vec![
+                Card(Rank::Ace, Suit::Spades),
+                Card(Rank::King, Suit::Diamonds),
+                Card(Rank::Seven, Suit::Clubs),
+            ]),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_razz_bring_in_suit_tie() {
+        assert_eq!(
+            Game::razz_bring_in(vec![
+                Card(Rank::Queen, Suit::Hearts),
+                Card(Rank::Queen, Suit::Spades),
+                Card(Rank::Queen, Suit::Clubs),
+            ]),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_razz_first_to_act() {
+        assert_eq!(
+            Game::razz_first_to_act(vec![
+                vec![Card(Rank::Two, Suit::Spades), Card(Rank::Two, Suit::Hearts)],
+                vec![
+                    Card(Rank::King, Suit::Spades),
+                    Card(Rank::Queen, Suit::Hearts)
+                ],
+                vec![Card(Rank::Ace, Suit::Clubs), Card(Rank::Six, Suit::Hearts)],
+                vec![
+                    Card(Rank::Four, Suit::Clubs),
+                    Card(Rank::Six, Suit::Diamonds)
+                ],
+            ]),
+            Some(2)
+        );
+    }
+
+    #[test]
+    fn test_razz_empty() {
+        assert_eq!(Game::razz_bring_in(vec![]), None);
+        assert_eq!(Game::razz_first_to_act(vec![]), None);
+    }
+}
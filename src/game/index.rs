@@ -0,0 +1,244 @@
+use std::convert::TryFrom;
+use std::io::Error;
+use std::io::ErrorKind;
+
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::card::Deck;
+use crate::combination::Variant;
+use crate::game::Board;
+use crate::game::Hand;
+use crate::game::HandOf2;
+use crate::game::HandOf3;
+use crate::game::HandOf4;
+use crate::game::HandOf5;
+use crate::game::HandOf7;
+
+// Ranks k-card subsets of the deck with the combinatorial number system, so
+// every subset maps to a unique integer in `0..count()` whatever the order of
+// its cards.
+pub trait Indexed: Sized + TryFrom<Vec<Card>, Error = Error> + 'static {
+    const SIZE: usize;
+
+    fn subset(&self) -> Vec<Card>;
+
+    fn count() -> usize {
+        binomial(52, Self::SIZE)
+    }
+
+    fn index(&self) -> Result<usize, Error> {
+        let indices = self
+            .subset()
+            .iter()
+            .map(|card| card.index())
+            .sorted()
+            .collect::<Vec<_>>();
+
+        if indices.windows(2).any(|pair| pair[0] == pair[1]) {
+            return Err(Error::new(ErrorKind::InvalidInput, "duplicate cards"));
+        }
+
+        Ok(indices
+            .into_iter()
+            .enumerate()
+            .map(|(k, index)| binomial(index, k + 1))
+            .sum())
+    }
+
+    fn from_index(index: usize) -> Option<Self> {
+        if index >= Self::count() {
+            return None;
+        }
+
+        let mut rest = index;
+        let mut cards = vec![];
+
+        for k in (1..=Self::SIZE).rev() {
+            let card = (0..52).rev().find(|n| binomial(*n, k) <= rest).unwrap();
+
+            rest -= binomial(card, k);
+            cards.push(Card::from_index(card).unwrap());
+        }
+
+        cards.reverse();
+
+        Self::try_from(cards).ok()
+    }
+
+    // Every subset that avoids the dead cards, with cards in deck order.
+    fn all(dead: &[Card]) -> Box<dyn Iterator<Item = Self>> {
+        let stub = Deck::list()
+            .into_iter()
+            .filter(|card| !dead.contains(card))
+            .collect::<Vec<_>>();
+
+        Box::new(
+            stub.into_iter()
+                .combinations(Self::SIZE)
+                .map(|cards| Self::try_from(cards).unwrap()),
+        )
+    }
+}
+
+pub(crate) fn binomial(n: usize, k: usize) -> usize {
+    if k > n {
+        return 0;
+    }
+
+    (0..k).fold(1, |acc, i| acc * (n - i) / (i + 1))
+}
+
+impl Indexed for Board {
+    const SIZE: usize = 5;
+
+    fn subset(&self) -> Vec<Card> {
+        self.cards()
+    }
+}
+
+impl Indexed for Variant {
+    const SIZE: usize = 5;
+
+    fn subset(&self) -> Vec<Card> {
+        self.cards()
+    }
+}
+
+impl Indexed for HandOf2 {
+    const SIZE: usize = 2;
+
+    fn subset(&self) -> Vec<Card> {
+        self.cards()
+    }
+}
+
+impl Indexed for HandOf3 {
+    const SIZE: usize = 3;
+
+    fn subset(&self) -> Vec<Card> {
+        self.cards()
+    }
+}
+
+impl Indexed for HandOf4 {
+    const SIZE: usize = 4;
+
+    fn subset(&self) -> Vec<Card> {
+        self.cards()
+    }
+}
+
+impl Indexed for HandOf5 {
+    const SIZE: usize = 5;
+
+    fn subset(&self) -> Vec<Card> {
+        self.cards()
+    }
+}
+
+impl Indexed for HandOf7 {
+    const SIZE: usize = 7;
+
+    fn subset(&self) -> Vec<Card> {
+        self.cards()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ::claim::*;
+
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::combination::Variant;
+    use crate::game::Board;
+    use crate::game::Hand;
+    use crate::game::HandOf2;
+    use crate::game::HandOf4;
+    use crate::game::HandOf7;
+    use crate::game::Indexed;
+
+    #[test]
+    fn test_counts() {
+        assert_eq!(HandOf2::count(), 1326);
+        assert_eq!(HandOf4::count(), 270725);
+        assert_eq!(Board::count(), 2598960);
+        assert_eq!(HandOf7::count(), 133784560);
+    }
+
+    #[test]
+    fn test_hand_of_2_bijection() {
+        let mut seen = vec![false; HandOf2::count()];
+
+        for hand in HandOf2::all(&[]) {
+            let index = hand.index().unwrap();
+
+            assert!(!seen[index]);
+            seen[index] = true;
+            assert_eq!(HandOf2::from_index(index).unwrap().cards(), hand.cards());
+        }
+
+        assert!(seen.into_iter().all(|seen| seen));
+        assert_none!(HandOf2::from_index(1326));
+    }
+
+    #[test]
+    fn test_order_independent() {
+        let cards = vec![
+            Card(Rank::Ace, Suit::Spades),
+            Card(Rank::Two, Suit::Diamonds),
+            Card(Rank::Nine, Suit::Hearts),
+            Card(Rank::Jack, Suit::Clubs),
+            Card(Rank::Four, Suit::Clubs),
+        ];
+        let board = Board::try_from(cards.clone()).unwrap();
+        let variant = Variant::try_from(cards.into_iter().rev().collect::<Vec<_>>()).unwrap();
+
+        assert_eq!(board.index().unwrap(), variant.index().unwrap());
+        assert_eq!(
+            Board::from_index(board.index().unwrap())
+                .unwrap()
+                .index()
+                .unwrap(),
+            board.index().unwrap()
+        );
+        assert_eq!(Board::from_index(0).unwrap().index().unwrap(), 0);
+        assert_eq!(
+            Board::from_index(Board::count() - 1).unwrap().cards(),
+            vec![
+                Card(Rank::King, Suit::Spades),
+                Card(Rank::Ace, Suit::Diamonds),
+                Card(Rank::Ace, Suit::Clubs),
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::Ace, Suit::Spades),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_duplicate_cards() {
+        let hand = HandOf2::new([Card(Rank::Ace, Suit::Spades), Card(Rank::Ace, Suit::Spades)]);
+
+        assert_err!(hand.index());
+    }
+
+    #[test]
+    fn test_all_excludes_dead() {
+        let dead = [
+            Card(Rank::Ace, Suit::Spades),
+            Card(Rank::King, Suit::Spades),
+        ];
+
+        let hands = HandOf2::all(&dead).collect::<Vec<_>>();
+
+        assert_eq!(hands.len(), 1225);
+        assert!(hands
+            .iter()
+            .all(|hand| hand.cards().iter().all(|card| !dead.contains(card))));
+        assert_eq!(HandOf4::all(&dead).count(), 230300);
+    }
+}
@@ -1,13 +1,48 @@
+pub mod badugi;
 pub mod board;
+pub mod double_board;
+pub mod draw;
+mod draws;
+mod equity;
 pub mod hands;
 mod impl_game;
+mod impl_stud;
+mod index;
+pub mod ofc;
+mod outs;
+pub mod pineapple;
 
+use crate::card::Card;
+use crate::combination::Combination;
+use crate::combination::Variant;
+
+pub use crate::game::badugi::Badugi;
 pub use crate::game::board::Board;
-pub use crate::game::hands::{Hand, HandOf2, HandOf4, HandOf5};
+pub use crate::game::double_board::DoubleBoard;
+pub use crate::game::draw::{Draw, DrawGame};
+pub use crate::game::draws::DrawKind;
+pub use crate::game::equity::Runouts;
+pub use crate::game::hands::{Hand, HandOf2, HandOf3, HandOf4, HandOf5, HandOf7};
+pub use crate::game::index::Indexed;
+pub use crate::game::ofc::Ofc;
+pub use crate::game::outs::Outs;
+pub use crate::game::pineapple::{Pineapple, PineappleGame};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Game {
     TexasHoldem(Board, Vec<HandOf2>),
     OmahaHoldem(Board, Vec<HandOf4>),
     FiveCardDraw(Vec<HandOf5>),
+    Razz(Vec<HandOf7>),
+    StudHiLo(Vec<HandOf7>),
+    DeuceToSevenDraw(Vec<HandOf5>),
+}
+
+// A player's best high hand and, when they qualify, their best low hand,
+// each with the five cards it uses.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HiLo {
+    pub(crate) hand: Vec<Card>,
+    pub(crate) high: (Combination, Variant),
+    pub(crate) low: Option<(Combination, Variant)>,
 }
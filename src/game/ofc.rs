@@ -0,0 +1,367 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use crate::card::Rank;
+use crate::combination::Combination;
+use crate::combination::Variant;
+use crate::game::Hand;
+use crate::game::HandOf3;
+use crate::game::HandOf5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ofc {
+    front: HandOf3,
+    middle: HandOf5,
+    back: HandOf5,
+}
+
+impl Ofc {
+    pub fn new(front: HandOf3, middle: HandOf5, back: HandOf5) -> Self {
+        Self {
+            front,
+            middle,
+            back,
+        }
+    }
+
+    pub fn front(&self) -> Combination {
+        let groups = self
+            .front
+            .cards()
+            .into_iter()
+            .fold(HashMap::new(), |mut acc, card| {
+                *acc.entry(card.rank()).or_insert(0) += 1;
+
+                acc
+            });
+
+        let rank_of = |n| {
+            groups
+                .iter()
+                .filter(|(_rank, m)| **m == n)
+                .map(|(rank, _)| *rank)
+                .max()
+        };
+
+        match (rank_of(3), rank_of(2), rank_of(1)) {
+            (Some(rank), _, _) => Combination::ThreeOfAKind { rank, kicker: rank },
+            (_, Some(rank), Some(kicker)) => Combination::Pair { rank, kicker },
+            (_, _, rank) => Combination::HighCard {
+                rank: rank.unwrap(),
+            },
+        }
+    }
+
+    pub fn middle(&self) -> Combination {
+        Combination::from_variant(Variant::try_from(self.middle.cards()).unwrap())
+    }
+
+    pub fn back(&self) -> Combination {
+        Combination::from_variant(Variant::try_from(self.back.cards()).unwrap())
+    }
+
+    pub fn is_foul(&self) -> bool {
+        let [front, middle, back] = self.strengths();
+
+        front > middle || middle > back
+    }
+
+    pub fn royalties(&self) -> i64 {
+        if self.is_foul() {
+            0
+        } else {
+            Self::front_royalty(self.front())
+                + Self::middle_royalty(self.middle())
+                + Self::back_royalty(self.back())
+        }
+    }
+
+    pub fn is_fantasyland(&self) -> bool {
+        !self.is_foul()
+            && self.front()
+                >= Combination::Pair {
+                    rank: Rank::Queen,
+                    kicker: Rank::Two,
+                }
+    }
+
+    pub fn score(&self, other: &Self) -> i64 {
+        let rows = match (self.is_foul(), other.is_foul()) {
+            (true, true) => 0,
+            (true, false) => -6,
+            (false, true) => 6,
+            (false, false) => {
+                let rows = self
+                    .strengths()
+                    .iter()
+                    .zip(other.strengths().iter())
+                    .map(|(row, other)| row.cmp(other))
+                    .map(|ord| match ord {
+                        Ordering::Greater => 1,
+                        Ordering::Equal => 0,
+                        Ordering::Less => -1,
+                    })
+                    .sum::<i64>();
+
+                match rows {
+                    3 => 6,
+                    -3 => -6,
+                    rows => rows,
+                }
+            }
+        };
+
+        rows + self.royalties() - other.royalties()
+    }
+
+    pub fn settle(hands: Vec<Self>) -> Vec<i64> {
+        hands
+            .iter()
+            .map(|hand| hands.iter().map(|other| hand.score(other)).sum())
+            .collect()
+    }
+
+    // Rows compare on every kicker, which `Combination` does not keep.
+    fn strengths(&self) -> [u32; 3] {
+        [
+            Combination::strength(&self.front.cards()),
+            Combination::strength(&self.middle.cards()),
+            Combination::strength(&self.back.cards()),
+        ]
+    }
+
+    fn front_royalty(combination: Combination) -> i64 {
+        match combination {
+            Combination::ThreeOfAKind { rank, kicker: _ } => 10 + rank as i64,
+            Combination::Pair { rank, kicker: _ } if rank >= Rank::Six => {
+                rank as i64 - Rank::Six as i64 + 1
+            }
+            _ => 0,
+        }
+    }
+
+    fn middle_royalty(combination: Combination) -> i64 {
+        match combination {
+            Combination::ThreeOfAKind { rank: _, kicker: _ } => 2,
+            combination => Self::back_royalty(combination) * 2,
+        }
+    }
+
+    fn back_royalty(combination: Combination) -> i64 {
+        match combination {
+            Combination::Straight { rank: _ } => 2,
+            Combination::Flush { rank: _ } => 4,
+            Combination::FullHouse { two: _, three: _ } => 6,
+            Combination::FourOfAKind { rank: _, kicker: _ } => 10,
+            Combination::StraightFlush { rank: Rank::Ten } => 25,
+            Combination::StraightFlush { rank: _ } => 15,
+            _ => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Ofc;
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::combination::Combination;
+    use crate::game::HandOf3;
+    use crate::game::HandOf5;
+
+    fn queens_trips_flush() -> Ofc {
+        Ofc::new(
+            HandOf3::new([
+                Card(Rank::Queen, Suit::Clubs),
+                Card(Rank::Queen, Suit::Diamonds),
+                Card(Rank::Two, Suit::Hearts),
+            ]),
+            HandOf5::new([
+                Card(Rank::Five, Suit::Clubs),
+                Card(Rank::Five, Suit::Diamonds),
+                Card(Rank::Five, Suit::Hearts),
+                Card(Rank::Nine, Suit::Spades),
+                Card(Rank::Jack, Suit::Clubs),
+            ]),
+            HandOf5::new([
+                Card(Rank::Two, Suit::Spades),
+                Card(Rank::Six, Suit::Spades),
+                Card(Rank::Eight, Suit::Spades),
+                Card(Rank::Ten, Suit::Spades),
+                Card(Rank::King, Suit::Spades),
+            ]),
+        )
+    }
+
+    fn high_cards() -> Ofc {
+        Ofc::new(
+            HandOf3::new([
+                Card(Rank::Ace, Suit::Clubs),
+                Card(Rank::Three, Suit::Diamonds),
+                Card(Rank::Four, Suit::Hearts),
+            ]),
+            HandOf5::new([
+                Card(Rank::Seven, Suit::Clubs),
+                Card(Rank::Seven, Suit::Diamonds),
+                Card(Rank::Three, Suit::Hearts),
+                Card(Rank::Nine, Suit::Clubs),
+                Card(Rank::Jack, Suit::Diamonds),
+            ]),
+            HandOf5::new([
+                Card(Rank::Eight, Suit::Clubs),
+                Card(Rank::Eight, Suit::Diamonds),
+                Card(Rank::Three, Suit::Clubs),
+                Card(Rank::Three, Suit::Spades),
+                Card(Rank::King, Suit::Hearts),
+            ]),
+        )
+    }
+
+    fn foul() -> Ofc {
+        Ofc::new(
+            HandOf3::new([
+                Card(Rank::King, Suit::Clubs),
+                Card(Rank::King, Suit::Diamonds),
+                Card(Rank::Four, Suit::Hearts),
+            ]),
+            HandOf5::new([
+                Card(Rank::Seven, Suit::Clubs),
+                Card(Rank::Seven, Suit::Diamonds),
+                Card(Rank::Three, Suit::Hearts),
+                Card(Rank::Nine, Suit::Clubs),
+                Card(Rank::Jack, Suit::Diamonds),
+            ]),
+            HandOf5::new([
+                Card(Rank::Ace, Suit::Clubs),
+                Card(Rank::Ace, Suit::Diamonds),
+                Card(Rank::Three, Suit::Clubs),
+                Card(Rank::Three, Suit::Spades),
+                Card(Rank::King, Suit::Hearts),
+            ]),
+        )
+    }
+
+    #[test]
+    fn test_front() {
+        assert_eq!(
+            queens_trips_flush().front(),
+            Combination::Pair {
+                rank: Rank::Queen,
+                kicker: Rank::Two
+            }
+        );
+        assert_eq!(
+            high_cards().front(),
+            Combination::HighCard { rank: Rank::Ace }
+        );
+    }
+
+    #[test]
+    fn test_is_foul() {
+        assert!(!queens_trips_flush().is_foul());
+        assert!(!high_cards().is_foul());
+        assert!(foul().is_foul());
+    }
+
+    #[test]
+    fn test_is_foul_on_kicker() {
+        let front = HandOf3::new([
+            Card(Rank::Ace, Suit::Clubs),
+            Card(Rank::King, Suit::Diamonds),
+            Card(Rank::Queen, Suit::Hearts),
+        ]);
+        let back = HandOf5::new([
+            Card(Rank::Two, Suit::Spades),
+            Card(Rank::Two, Suit::Hearts),
+            Card(Rank::Three, Suit::Clubs),
+            Card(Rank::Three, Suit::Spades),
+            Card(Rank::Four, Suit::Hearts),
+        ]);
+
+        assert!(Ofc::new(
+            front,
+            HandOf5::new([
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::King, Suit::Clubs),
+                Card(Rank::Jack, Suit::Diamonds),
+                Card(Rank::Nine, Suit::Spades),
+                Card(Rank::Eight, Suit::Clubs),
+            ]),
+            back,
+        )
+        .is_foul());
+        assert!(!Ofc::new(
+            front,
+            HandOf5::new([
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::King, Suit::Clubs),
+                Card(Rank::Queen, Suit::Diamonds),
+                Card(Rank::Nine, Suit::Spades),
+                Card(Rank::Eight, Suit::Clubs),
+            ]),
+            back,
+        )
+        .is_foul());
+    }
+
+    #[test]
+    fn test_score_on_kicker() {
+        let row = |kicker| {
+            Ofc::new(
+                HandOf3::new([
+                    Card(Rank::Two, Suit::Clubs),
+                    Card(Rank::Three, Suit::Diamonds),
+                    Card(Rank::Four, Suit::Hearts),
+                ]),
+                HandOf5::new([
+                    Card(Rank::Ace, Suit::Hearts),
+                    Card(Rank::King, Suit::Clubs),
+                    Card(Rank::Queen, Suit::Diamonds),
+                    Card(Rank::Nine, Suit::Spades),
+                    Card(kicker, Suit::Clubs),
+                ]),
+                HandOf5::new([
+                    Card(Rank::Two, Suit::Spades),
+                    Card(Rank::Two, Suit::Hearts),
+                    Card(Rank::Three, Suit::Clubs),
+                    Card(Rank::Three, Suit::Spades),
+                    Card(Rank::Four, Suit::Diamonds),
+                ]),
+            )
+        };
+
+        assert_eq!(row(Rank::Eight).score(&row(Rank::Seven)), 1);
+        assert_eq!(row(Rank::Seven).score(&row(Rank::Eight)), -1);
+    }
+
+    #[test]
+    fn test_royalties() {
+        assert_eq!(queens_trips_flush().royalties(), 7 + 2 + 4);
+        assert_eq!(high_cards().royalties(), 0);
+        assert_eq!(foul().royalties(), 0);
+    }
+
+    #[test]
+    fn test_is_fantasyland() {
+        assert!(queens_trips_flush().is_fantasyland());
+        assert!(!high_cards().is_fantasyland());
+        assert!(!foul().is_fantasyland());
+    }
+
+    #[test]
+    fn test_score() {
+        assert_eq!(queens_trips_flush().score(&high_cards()), 6 + 13);
+        assert_eq!(high_cards().score(&foul()), 6);
+        assert_eq!(foul().score(&queens_trips_flush()), -6 - 13);
+    }
+
+    #[test]
+    fn test_settle() {
+        assert_eq!(
+            Ofc::settle(vec![queens_trips_flush(), high_cards(), foul()]),
+            vec![38, -13, -25]
+        );
+    }
+}
@@ -0,0 +1,248 @@
+use std::convert::TryInto;
+use std::io::Error;
+use std::io::ErrorKind;
+
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::card::Deck;
+use crate::combination::Class;
+use crate::combination::Combination;
+use crate::combination::Variant;
+use crate::game::Hand;
+use crate::game::HandOf2;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Outs {
+    outs: Vec<(Card, Combination)>,
+    tainted: Vec<(Card, Combination)>,
+}
+
+impl Outs {
+    // Without an opponent, an out is any card that lifts the hand to a better
+    // class that the board plus that card does not make on its own, so cards
+    // that only pair the board are not outs. Against an opponent it must also
+    // leave the hand ahead; cards that improve the hand but also improve the
+    // opponent and leave them ahead are tainted.
+    pub fn texas_holdem(
+        hand: HandOf2,
+        board: &[Card],
+        opponent: Option<HandOf2>,
+        dead: &[Card],
+    ) -> Result<Self, Error> {
+        if board.len() != 3 && board.len() != 4 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "outs need a flop or a turn",
+            ));
+        }
+
+        let known = hand
+            .cards()
+            .into_iter()
+            .chain(board.iter().copied())
+            .chain(opponent.iter().flat_map(|hand| hand.cards()))
+            .chain(dead.iter().copied())
+            .collect::<Vec<_>>();
+
+        if known.iter().unique().count() != known.len() {
+            return Err(Error::new(ErrorKind::InvalidInput, "duplicate cards"));
+        }
+
+        let current = Self::strength(hand, board);
+        let opponent_current = opponent.map(|opponent| Self::strength(opponent, board));
+
+        let mut outs = vec![];
+        let mut tainted = vec![];
+
+        for card in Deck::list()
+            .into_iter()
+            .filter(|card| !known.contains(card))
+        {
+            let board = [board, &[card]].concat();
+            let next = Self::strength(hand, &board);
+            let improved = Self::improved(current, next, &board);
+            let out = (card, Self::best(hand, &board));
+
+            match opponent.zip(opponent_current) {
+                None if improved => outs.push(out),
+                None => (),
+                Some((opponent, opponent_current)) => {
+                    let opponent_next = Self::strength(opponent, &board);
+
+                    if next > opponent_next && (improved || current <= opponent_current) {
+                        outs.push(out);
+                    } else if improved && Self::improved(opponent_current, opponent_next, &board) {
+                        tainted.push(out);
+                    }
+                }
+            }
+        }
+
+        Ok(Self { outs, tainted })
+    }
+
+    pub fn cards(&self) -> Vec<Card> {
+        self.outs.iter().map(|(card, _)| *card).collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.outs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.outs.is_empty()
+    }
+
+    // Strongest class first.
+    pub fn by_class(&self) -> Vec<(Class, Vec<Card>)> {
+        Self::group(&self.outs)
+    }
+
+    pub fn tainted(&self) -> Vec<Card> {
+        self.tainted.iter().map(|(card, _)| *card).collect()
+    }
+
+    pub fn tainted_by_class(&self) -> Vec<(Class, Vec<Card>)> {
+        Self::group(&self.tainted)
+    }
+
+    fn group(outs: &[(Card, Combination)]) -> Vec<(Class, Vec<Card>)> {
+        outs.iter()
+            .map(|(card, combination)| (combination.class().unwrap(), *card))
+            .into_group_map()
+            .into_iter()
+            .sorted_by_key(|(class, _)| std::cmp::Reverse(*class))
+            .collect()
+    }
+
+    // A better class whose made cards the board alone does not match.
+    fn improved(current: u32, next: u32, board: &[Card]) -> bool {
+        next >> 20 > current >> 20
+            && Combination::without_kickers(next)
+                > Combination::without_kickers(Combination::strength(board))
+    }
+
+    fn strength(hand: HandOf2, board: &[Card]) -> u32 {
+        Combination::strength(&[&hand.cards()[..], board].concat())
+    }
+
+    fn best(hand: HandOf2, board: &[Card]) -> Combination {
+        hand.cards()
+            .into_iter()
+            .chain(board.iter().copied())
+            .combinations(5)
+            .map(|cards| Combination::from_variant(Variant(cards.try_into().unwrap())))
+            .max()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::combination::Class;
+    use crate::game::HandOf2;
+    use crate::game::Outs;
+
+    #[test]
+    fn test_outs_without_opponent() {
+        let outs = Outs::texas_holdem(
+            HandOf2::new([
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::King, Suit::Hearts),
+            ]),
+            &[
+                Card(Rank::Two, Suit::Hearts),
+                Card(Rank::Seven, Suit::Hearts),
+                Card(Rank::Nine, Suit::Clubs),
+            ],
+            None,
+            &[],
+        )
+        .unwrap();
+
+        let by_class = outs.by_class();
+
+        assert_eq!(outs.len(), 15);
+        assert_eq!(by_class[0].0, Class::Flush);
+        assert_eq!(by_class[0].1.len(), 9);
+        assert_eq!(by_class[1].0, Class::Pair);
+        assert_eq!(by_class[1].1.len(), 6);
+        assert!(outs.tainted().is_empty());
+    }
+
+    #[test]
+    fn test_board_only_improvements() {
+        let outs = Outs::texas_holdem(
+            HandOf2::new([
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::King, Suit::Spades),
+            ]),
+            &[
+                Card(Rank::Nine, Suit::Clubs),
+                Card(Rank::Nine, Suit::Diamonds),
+                Card(Rank::Two, Suit::Clubs),
+            ],
+            None,
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(outs.len(), 6);
+        assert_eq!(outs.by_class(), vec![(Class::TwoPairs, outs.cards())]);
+        assert!(!outs.cards().contains(&Card(Rank::Nine, Suit::Hearts)));
+        assert!(!outs.cards().contains(&Card(Rank::Two, Suit::Spades)));
+    }
+
+    #[test]
+    fn test_tainted_outs() {
+        let outs = Outs::texas_holdem(
+            HandOf2::new([
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::King, Suit::Hearts),
+            ]),
+            &[
+                Card(Rank::Two, Suit::Hearts),
+                Card(Rank::Seven, Suit::Hearts),
+                Card(Rank::Queen, Suit::Spades),
+            ],
+            Some(HandOf2::new([
+                Card(Rank::Queen, Suit::Clubs),
+                Card(Rank::Queen, Suit::Diamonds),
+            ])),
+            &[],
+        )
+        .unwrap();
+
+        assert_eq!(outs.by_class(), vec![(Class::Flush, outs.cards())]);
+        assert_eq!(outs.len(), 8);
+        assert!(!outs.cards().contains(&Card(Rank::Queen, Suit::Hearts)));
+        assert!(outs.tainted().contains(&Card(Rank::Queen, Suit::Hearts)));
+        assert_eq!(outs.tainted(), vec![Card(Rank::Queen, Suit::Hearts)]);
+    }
+
+    #[test]
+    fn test_invalid() {
+        let hand = HandOf2::new([
+            Card(Rank::Ace, Suit::Hearts),
+            Card(Rank::King, Suit::Hearts),
+        ]);
+
+        assert_err!(Outs::texas_holdem(hand, &[], None, &[]));
+        assert_err!(Outs::texas_holdem(
+            hand,
+            &[
+                Card(Rank::Ace, Suit::Hearts),
+                Card(Rank::Seven, Suit::Hearts),
+                Card(Rank::Queen, Suit::Spades),
+            ],
+            None,
+            &[],
+        ));
+    }
+}
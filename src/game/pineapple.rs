@@ -0,0 +1,218 @@
+use std::convert::TryFrom;
+use std::io::Error;
+use std::io::ErrorKind;
+
+use crate::card::Card;
+use crate::card::Deck;
+use crate::game::Board;
+use crate::game::Game;
+use crate::game::HandOf2;
+use crate::game::HandOf3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PineappleGame {
+    Pineapple,
+    CrazyPineapple,
+}
+
+impl PineappleGame {
+    pub fn discard_street(&self) -> usize {
+        match self {
+            Self::Pineapple => 0,
+            Self::CrazyPineapple => 3,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Pineapple {
+    game: PineappleGame,
+    deck: Deck,
+    board: Vec<Card>,
+    hands: Vec<Vec<Card>>,
+}
+
+impl Pineapple {
+    pub fn new(game: PineappleGame, mut deck: Deck, players: usize) -> Result<Self, Error> {
+        if players < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "at least two players are required",
+            ));
+        }
+
+        // Three hole cards each and a five-card board.
+        if players * 3 + 5 > deck.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "not enough cards to deal",
+            ));
+        }
+
+        let mut hands = vec![vec![]; players];
+
+        for _ in 0..3 {
+            for hand in hands.iter_mut() {
+                hand.extend(deck.deal(1).unwrap());
+            }
+        }
+
+        Ok(Self {
+            game,
+            deck,
+            board: vec![],
+            hands,
+        })
+    }
+
+    pub fn game(&self) -> PineappleGame {
+        self.game
+    }
+
+    pub fn board(&self) -> Vec<Card> {
+        self.board.clone()
+    }
+
+    pub fn hands(&self) -> Vec<Vec<Card>> {
+        self.hands.clone()
+    }
+
+    pub fn discard(&mut self, player: usize, card: Card) -> Result<HandOf2, Error> {
+        if self.board.len() != self.game.discard_street() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "discards are not allowed on this street",
+            ));
+        }
+
+        let hand = self
+            .hands
+            .get(player)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "no such player"))?;
+
+        if hand.len() != 3 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "player has already discarded",
+            ));
+        }
+
+        let hand = HandOf3::try_from(hand.clone())?
+            .discard(card)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "discarded card is not in hand"))?;
+
+        self.hands[player].retain(|c| *c != card);
+
+        Ok(hand)
+    }
+
+    pub fn deal(&mut self) -> Result<Vec<Card>, Error> {
+        if self.board.len() == self.game.discard_street()
+            && self.hands.iter().any(|hand| hand.len() == 3)
+        {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "every player has to discard first",
+            ));
+        }
+
+        let cards = match self.board.len() {
+            0 => self.deck.deal(3).unwrap(),
+            3 | 4 => self.deck.deal(1).unwrap(),
+            _ => return Err(Error::new(ErrorKind::InvalidInput, "board is complete")),
+        };
+
+        self.board.extend(cards.iter().copied());
+
+        Ok(cards)
+    }
+
+    pub fn showdown(&self) -> Result<Vec<Vec<Vec<Card>>>, Error> {
+        if self.board.len() != 5 {
+            return Err(Error::new(ErrorKind::InvalidInput, "board is not complete"));
+        }
+
+        let game = Game::TexasHoldem(
+            Board::try_from(self.board.clone()).unwrap(),
+            self.hands
+                .iter()
+                .map(|hand| HandOf2::try_from(hand.clone()).unwrap())
+                .collect(),
+        );
+
+        Ok(Game::sort_hands(Game::group_hands(
+            Game::rank_hands(game)
+                .into_iter()
+                .map(|(hand, combination, _variant)| (hand, combination))
+                .collect(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use super::{Pineapple, PineappleGame};
+    use crate::card::Deck;
+
+    #[test]
+    fn test_pineapple() {
+        let mut game = Pineapple::new(PineappleGame::Pineapple, Deck::new(0), 3).unwrap();
+
+        assert!(game.hands().iter().all(|hand| hand.len() == 3));
+        assert_err!(game.deal());
+
+        for player in 0..3 {
+            let card = game.hands()[player][0];
+            assert_ok!(game.discard(player, card));
+            assert_err!(game.discard(player, card));
+        }
+
+        assert_eq!(game.deal().unwrap().len(), 3);
+        assert_err!(game.discard(0, game.hands()[0][0]));
+        assert_eq!(game.deal().unwrap().len(), 1);
+        assert_err!(game.showdown());
+        assert_eq!(game.deal().unwrap().len(), 1);
+        assert_err!(game.deal());
+
+        assert_eq!(game.showdown().unwrap().concat().len(), 3);
+    }
+
+    #[test]
+    fn test_crazy_pineapple() {
+        let mut game = Pineapple::new(PineappleGame::CrazyPineapple, Deck::new(0), 2).unwrap();
+
+        assert_err!(game.discard(0, game.hands()[0][0]));
+        assert_eq!(game.deal().unwrap().len(), 3);
+        assert_err!(game.deal());
+
+        for player in 0..2 {
+            let card = game.hands()[player][2];
+            assert_ok!(game.discard(player, card));
+        }
+
+        assert_ok!(game.deal());
+        assert_ok!(game.deal());
+        assert_eq!(game.showdown().unwrap().concat().len(), 2);
+    }
+
+    #[test]
+    fn test_player_count() {
+        assert_err!(Pineapple::new(PineappleGame::Pineapple, Deck::new(0), 1));
+        assert_err!(Pineapple::new(PineappleGame::Pineapple, Deck::new(0), 16));
+
+        let mut game = Pineapple::new(PineappleGame::Pineapple, Deck::new(0), 15).unwrap();
+
+        for player in 0..15 {
+            let card = game.hands()[player][0];
+            assert_ok!(game.discard(player, card));
+        }
+
+        for _ in 0..3 {
+            assert_ok!(game.deal());
+        }
+
+        assert_eq!(game.board().len(), 5);
+    }
+}
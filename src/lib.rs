@@ -1,16 +1,67 @@
+mod canonical;
+pub use canonical::BoardIndex;
+pub use canonical::Canonical;
+
 mod card;
 pub use card::Card;
+pub use card::Deck;
 pub use card::Rank;
 pub use card::Suit;
 
 mod combination;
+pub use combination::Class;
 pub use combination::Combination;
 pub use combination::Variant;
 
 mod game;
+pub use game::Badugi;
 pub use game::Board;
+pub use game::DoubleBoard;
+pub use game::Draw;
+pub use game::DrawGame;
+pub use game::DrawKind;
 pub use game::Game;
 pub use game::Hand;
 pub use game::HandOf2;
+pub use game::HandOf3;
 pub use game::HandOf4;
 pub use game::HandOf5;
+pub use game::HandOf7;
+pub use game::HiLo;
+pub use game::Indexed;
+pub use game::Ofc;
+pub use game::Outs;
+pub use game::Pineapple;
+pub use game::PineappleGame;
+pub use game::Runouts;
+
+mod preflop;
+pub use preflop::Equities;
+pub use preflop::Range;
+pub use preflop::StartingHand;
+
+mod table;
+pub use table::Action;
+pub use table::Ante;
+pub use table::Config;
+pub use table::Event;
+pub use table::Fee;
+pub use table::Legal;
+pub use table::OddChip;
+pub use table::Pot;
+pub use table::Rake;
+pub use table::Returning;
+pub use table::Seat;
+pub use table::SeatView;
+pub use table::Straddle;
+pub use table::Street;
+pub use table::Structure;
+pub use table::Table;
+pub use table::View;
+pub use table::Viewer;
+
+mod tournament;
+pub use tournament::Level;
+pub use tournament::Move;
+pub use tournament::Seating;
+pub use tournament::Tournament;
@@ -0,0 +1,281 @@
+use std::collections::HashMap;
+use std::io::Error;
+use std::io::ErrorKind;
+
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::card::Deck;
+use crate::combination::Combination;
+use crate::game::Hand;
+use crate::game::HandOf2;
+use crate::game::Indexed;
+use crate::preflop::Equities;
+use crate::preflop::StartingHand;
+
+// Generated by `examples/preflop_equities.rs` from `Equities::enumerated`.
+const PREFLOP: &[u8] = include_bytes!("equities.bin");
+
+// Boards left once both hands are dealt, C(48, 5).
+const BOARDS: u64 = 1_712_304;
+
+impl Equities {
+    // `equities[a * 169 + b]` is the all-in equity of class `a` against
+    // class `b`.
+    pub fn new(equities: Vec<f64>) -> Result<Self, Error> {
+        if equities.len() != 169 * 169 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "expected an equity for every pair of starting hands",
+            ));
+        }
+
+        Ok(Self(equities))
+    }
+
+    // Entries are exact equities rounded to a basis point.
+    pub fn preflop() -> Self {
+        Self(
+            PREFLOP
+                .chunks(2)
+                .map(|bytes| u16::from_le_bytes([bytes[0], bytes[1]]) as f64 / 10_000.0)
+                .collect(),
+        )
+    }
+
+    // Exact equities over every board for every card-compatible pair of
+    // concrete hands. Boards are walked once per suit isomorphism class and
+    // weighted by its size, which keeps this to about a minute in a
+    // release build.
+    pub fn enumerated() -> Self {
+        let hands = Self::concrete();
+        let mut wins = vec![0u64; 169 * 169];
+
+        for (board, weight) in Self::boards() {
+            Self::board_wins(&board, &hands)
+                .into_iter()
+                .enumerate()
+                .for_each(|(n, won)| wins[n] += won * weight);
+        }
+
+        let mut pairs = vec![0u64; 169 * 169];
+
+        for ((x, _, a), (y, _, b)) in hands.iter().tuple_combinations() {
+            if x.iter().all(|card| !y.contains(card)) {
+                pairs[a * 169 + b] += BOARDS;
+                pairs[b * 169 + a] += BOARDS;
+            }
+        }
+
+        Self(
+            (0..169 * 169)
+                .map(|n| {
+                    let (a, b) = (n / 169, n % 169);
+
+                    if a == b {
+                        return 0.5;
+                    }
+
+                    let ties = pairs[n] - wins[n] - wins[b * 169 + a];
+
+                    (wins[n] as f64 + ties as f64 / 2.0) / pairs[n] as f64
+                })
+                .collect(),
+        )
+    }
+
+    pub fn equity(&self, hand: StartingHand, other: StartingHand) -> f64 {
+        self.0[hand.index() * 169 + other.index()]
+    }
+
+    // Every hand of two cards with its deck indices and its class index.
+    fn concrete() -> Vec<(Vec<Card>, [usize; 2], usize)> {
+        HandOf2::all(&[])
+            .map(|hand| {
+                let cards = hand.cards();
+                let indices = [cards[0].index(), cards[1].index()];
+
+                (cards, indices, StartingHand::from_hand(hand).index())
+            })
+            .collect()
+    }
+
+    // One board per suit isomorphism class with the number of boards in it.
+    // Two boards are isomorphic when they hold the same sets of ranks in
+    // their suits, whichever suits those are.
+    fn boards() -> Vec<(Vec<Card>, u64)> {
+        let deck = Deck::list();
+        let suits = deck
+            .iter()
+            .map(|card| card.suit().precedence() as usize)
+            .collect::<Vec<_>>();
+        let mut classes: HashMap<[u16; 4], (Vec<usize>, u64)> = HashMap::new();
+
+        for board in (0..52).combinations(5) {
+            let mut key = [0u16; 4];
+
+            board
+                .iter()
+                .for_each(|card| key[suits[*card]] |= 1 << deck[*card].rank() as u16);
+            key.sort_unstable();
+
+            classes.entry(key).or_insert((board, 0)).1 += 1;
+        }
+
+        classes
+            .into_iter()
+            .map(|(_, (board, weight))| {
+                (board.into_iter().map(|card| deck[card]).collect(), weight)
+            })
+            .collect()
+    }
+
+    // `wins[a * 169 + b]` counts the card-compatible pairs of concrete hands
+    // in classes `a` and `b` that the hand in `a` wins on this board. Hands
+    // are visited from weakest to strongest, and for each one the weaker
+    // hands it collides with are taken out by the cards they share.
+    fn board_wins(board: &[Card], hands: &[(Vec<Card>, [usize; 2], usize)]) -> Vec<u64> {
+        let live = hands
+            .iter()
+            .filter(|(cards, _, _)| cards.iter().all(|card| !board.contains(card)))
+            .map(|(cards, indices, class)| {
+                let strength = Combination::strength(&[
+                    cards[0], cards[1], board[0], board[1], board[2], board[3], board[4],
+                ]);
+
+                (strength, *indices, *class)
+            })
+            .sorted_by_key(|(strength, _, _)| *strength)
+            .collect::<Vec<_>>();
+
+        let mut wins = vec![0u64; 169 * 169];
+        let mut below = vec![0u64; 169];
+        let mut below_card = vec![0u64; 52 * 169];
+        let mut start = 0;
+
+        while start < live.len() {
+            let end = start
+                + live[start..]
+                    .iter()
+                    .take_while(|(strength, _, _)| *strength == live[start].0)
+                    .count();
+
+            for (_, [x, y], class) in live[start..end].iter() {
+                let row = &mut wins[class * 169..(class + 1) * 169];
+                let (x, y) = (&below_card[x * 169..], &below_card[y * 169..]);
+
+                for b in 0..169 {
+                    row[b] += below[b] - x[b] - y[b];
+                }
+            }
+
+            for (_, [x, y], class) in live[start..end].iter() {
+                below[*class] += 1;
+                below_card[x * 169 + class] += 1;
+                below_card[y * 169 + class] += 1;
+            }
+
+            start = end;
+        }
+
+        wins
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use ::claim::*;
+
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::combination::Combination;
+    use crate::game::Board;
+    use crate::game::Game;
+    use crate::game::HandOf2;
+    use crate::preflop::Equities;
+
+    #[test]
+    fn test_new() {
+        assert_err!(Equities::new(vec![0.5; 10]));
+        assert_ok!(Equities::new(vec![0.5; 169 * 169]));
+    }
+
+    #[test]
+    fn test_preflop() {
+        let equities = Equities::preflop();
+        let equity = |a: &str, b: &str| equities.equity(a.parse().unwrap(), b.parse().unwrap());
+
+        // Exact equities, as found by comparing the two hands on every
+        // board for every pair of concrete hands.
+        assert_eq!(equities.0.len(), 169 * 169);
+        assert_eq!(equity("AA", "KK"), 0.8195);
+        assert_eq!(equity("AKs", "QQ"), 0.4605);
+        assert_eq!(equity("72o", "AA"), 0.118);
+        assert_eq!(equity("AKo", "AQo"), 0.7439);
+        assert_eq!(equity("T9s", "87s"), 0.6445);
+        assert_eq!(equity("T9s", "T9s"), 0.5);
+
+        (0..169 * 169).for_each(|n| {
+            let (a, b) = (n / 169, n % 169);
+
+            assert_lt!(
+                (equities.0[a * 169 + b] + equities.0[b * 169 + a] - 1.0).abs(),
+                1e-9
+            );
+        });
+    }
+
+    #[test]
+    fn test_boards() {
+        let boards = Equities::boards();
+
+        assert_eq!(
+            boards.iter().map(|(_, weight)| weight).sum::<u64>(),
+            2_598_960
+        );
+        assert_eq!(boards.len(), 134_459);
+    }
+
+    // Checks the per-board counts against every pair of concrete hands
+    // ranked through `texas_holdem_combination`.
+    #[test]
+    fn test_board_wins() {
+        let board = vec![
+            Card(Rank::Ace, Suit::Spades),
+            Card(Rank::Seven, Suit::Spades),
+            Card(Rank::Seven, Suit::Diamonds),
+            Card(Rank::Jack, Suit::Clubs),
+            Card(Rank::Four, Suit::Spades),
+        ];
+        let hands = Equities::concrete();
+        let wins = Equities::board_wins(&board, &hands);
+
+        let ranked = hands
+            .iter()
+            .filter(|(cards, _, _)| cards.iter().all(|card| !board.contains(card)))
+            .map(|(cards, _, class)| {
+                let (_, variant) = Game::texas_holdem_combination(
+                    Board::try_from(board.clone()).unwrap(),
+                    HandOf2::try_from(cards.clone()).unwrap(),
+                );
+
+                (cards, Combination::strength(&variant.cards()), *class)
+            })
+            .collect::<Vec<_>>();
+
+        let mut expected = vec![0u64; 169 * 169];
+
+        for (x, strength_x, a) in ranked.iter() {
+            for (y, strength_y, b) in ranked.iter() {
+                if x.iter().all(|card| !y.contains(card)) && strength_x > strength_y {
+                    expected[a * 169 + b] += 1;
+                }
+            }
+        }
+
+        assert_eq!(wins, expected);
+    }
+}
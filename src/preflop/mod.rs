@@ -0,0 +1,19 @@
+mod equities;
+mod push_fold;
+mod range;
+mod starting_hand;
+
+use crate::card::Rank;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StartingHand {
+    pub(crate) high: Rank,
+    pub(crate) low: Rank,
+    pub(crate) suited: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range(pub(crate) [bool; 169]);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equities(pub(crate) Vec<f64>);
@@ -0,0 +1,177 @@
+use crate::game::Hand;
+use crate::preflop::Equities;
+use crate::preflop::Range;
+use crate::preflop::StartingHand;
+
+const ITERATIONS: usize = 500;
+
+impl Range {
+    // Heads-up push/fold: the small blind shoves `stack` chips or folds,
+    // the big blind calls or folds. Both ranges are found by fictitious
+    // play, so mixed hands end up in the range they are played more often.
+    pub fn push_fold(
+        equities: &Equities,
+        stack: u64,
+        small_blind: u64,
+        big_blind: u64,
+        ante: u64,
+    ) -> (Self, Self) {
+        let weights = Self::weights();
+        let stack = stack as f64;
+        let fold_small_blind = -((small_blind + ante) as f64);
+        let fold_big_blind = -((big_blind + ante) as f64);
+        let steal = (big_blind + ante) as f64;
+
+        let mut push = vec![1.0; 169];
+        let mut call = vec![1.0; 169];
+
+        for iteration in 1..=ITERATIONS {
+            let step = 1.0 / (iteration + 1) as f64;
+
+            let best_push = (0..169)
+                .map(|a| {
+                    let (ev, total) = (0..169).fold((0.0, 0.0), |(ev, total), b| {
+                        let w = weights[a * 169 + b];
+                        let called = w * call[b];
+                        let showdown = equities.0[a * 169 + b] * 2.0 * stack - stack;
+
+                        (ev + called * showdown + (w - called) * steal, total + w)
+                    });
+
+                    ev / total > fold_small_blind
+                })
+                .collect::<Vec<_>>();
+
+            let best_call = (0..169)
+                .map(|b| {
+                    let (ev, total) = (0..169).fold((0.0, 0.0), |(ev, total), a| {
+                        let w = weights[a * 169 + b] * push[a];
+                        let showdown = equities.0[b * 169 + a] * 2.0 * stack - stack;
+
+                        (ev + w * showdown, total + w)
+                    });
+
+                    total > 0.0 && ev / total > fold_big_blind
+                })
+                .collect::<Vec<_>>();
+
+            for n in 0..169 {
+                push[n] += step * (if best_push[n] { 1.0 } else { 0.0 } - push[n]);
+                call[n] += step * (if best_call[n] { 1.0 } else { 0.0 } - call[n]);
+            }
+        }
+
+        let range = |frequencies: Vec<f64>| {
+            let mut range = Self::empty();
+
+            (0..169)
+                .filter(|n| frequencies[*n] > 0.5)
+                .for_each(|n| range.add(StartingHand::from_index(n)));
+
+            range
+        };
+
+        (range(push), range(call))
+    }
+
+    // Number of card-compatible combinations for every pair of classes.
+    fn weights() -> Vec<f64> {
+        let hands = StartingHand::all()
+            .iter()
+            .map(|hand| {
+                hand.hands()
+                    .iter()
+                    .map(|hand| hand.cards())
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut weights = vec![0.0; 169 * 169];
+
+        for a in 0..169 {
+            for b in 0..169 {
+                weights[a * 169 + b] = hands[a]
+                    .iter()
+                    .flat_map(|x| hands[b].iter().map(move |y| (x, y)))
+                    .filter(|(x, y)| x.iter().all(|card| !y.contains(card)))
+                    .count() as f64;
+            }
+        }
+
+        weights
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::preflop::Equities;
+    use crate::preflop::Range;
+    use crate::preflop::StartingHand;
+
+    // A stand-in for real equities: stronger classes by a simple score
+    // are favourites in proportion to the score difference.
+    fn equities() -> Equities {
+        let score = |hand: StartingHand| {
+            hand.high() as usize as f64
+                + hand.low() as usize as f64
+                + if hand.is_pair() { 12.0 } else { 0.0 }
+                + if hand.is_suited() { 1.0 } else { 0.0 }
+        };
+
+        let equities = (0..169 * 169)
+            .map(|n| {
+                let a = score(StartingHand::from_index(n / 169));
+                let b = score(StartingHand::from_index(n % 169));
+
+                (0.5 + (a - b) / 80.0).clamp(0.1, 0.9)
+            })
+            .collect();
+
+        Equities::new(equities).unwrap()
+    }
+
+    #[test]
+    fn test_push_fold() {
+        let equities = equities();
+        let aces = "AA".parse::<StartingHand>().unwrap();
+        let trash = "72o".parse::<StartingHand>().unwrap();
+
+        let (push, call) = Range::push_fold(&equities, 2, 1, 2, 0);
+
+        assert!(push.contains(trash));
+        assert!(call.contains(aces));
+
+        let (deep_push, deep_call) = Range::push_fold(&equities, 50, 1, 2, 0);
+
+        assert!(deep_push.contains(aces));
+        assert!(deep_call.contains(aces));
+        assert!(!deep_push.contains(trash));
+        assert!(!deep_call.contains(trash));
+        assert!(deep_push.combos() < push.combos());
+        assert!(deep_call.combos() < call.combos());
+    }
+
+    #[test]
+    fn test_antes_widen_ranges() {
+        let equities = equities();
+
+        let (push, _) = Range::push_fold(&equities, 20, 1, 2, 0);
+        let (push_with_antes, _) = Range::push_fold(&equities, 20, 1, 2, 1);
+
+        assert!(push_with_antes.combos() >= push.combos());
+    }
+
+    #[test]
+    fn test_push_fold_preflop() {
+        let equities = Equities::preflop();
+        let hand = |hand: &str| hand.parse::<StartingHand>().unwrap();
+
+        let (push, call) = Range::push_fold(&equities, 20, 1, 2, 0);
+
+        assert!(push.contains(hand("AA")));
+        assert!(push.contains(hand("A2o")));
+        assert!(call.contains(hand("AA")));
+        assert!(!call.contains(hand("72o")));
+        assert!(push.combos() > call.combos());
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+
+use crate::preflop::Range;
+use crate::preflop::StartingHand;
+
+impl Range {
+    pub fn empty() -> Self {
+        Self([false; 169])
+    }
+
+    pub fn full() -> Self {
+        Self([true; 169])
+    }
+
+    pub fn from_hands(hands: &[StartingHand]) -> Self {
+        let mut range = Self::empty();
+
+        hands.iter().for_each(|hand| range.add(*hand));
+
+        range
+    }
+
+    pub fn add(&mut self, hand: StartingHand) {
+        self.0[hand.index()] = true;
+    }
+
+    pub fn remove(&mut self, hand: StartingHand) {
+        self.0[hand.index()] = false;
+    }
+
+    pub fn contains(&self, hand: StartingHand) -> bool {
+        self.0[hand.index()]
+    }
+
+    pub fn hands(&self) -> Vec<StartingHand> {
+        StartingHand::all()
+            .into_iter()
+            .filter(|hand| self.contains(*hand))
+            .collect()
+    }
+
+    pub fn combos(&self) -> usize {
+        self.hands().iter().map(|hand| hand.combos()).sum()
+    }
+
+    pub fn percent(&self) -> f64 {
+        self.combos() as f64 / 1326.0 * 100.0
+    }
+}
+
+impl fmt::Display for Range {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..13 {
+            let line = (0..13)
+                .map(|col| StartingHand::from_index(row * 13 + col))
+                .map(|hand| {
+                    if self.contains(hand) {
+                        format!("{:<4}", hand.to_string())
+                    } else {
+                        format!("{:<4}", "-")
+                    }
+                })
+                .collect::<String>();
+
+            writeln!(f, "{}", line.trim_end())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::preflop::Range;
+    use crate::preflop::StartingHand;
+
+    #[test]
+    fn test_range() {
+        let hands = ["AA", "KK", "AKs", "AKo"]
+            .iter()
+            .map(|hand| hand.parse::<StartingHand>().unwrap())
+            .collect::<Vec<_>>();
+
+        let mut range = Range::from_hands(&hands);
+
+        assert_eq!(range.combos(), 6 + 6 + 4 + 12);
+        assert!(range.contains(hands[2]));
+
+        range.remove(hands[2]);
+
+        assert!(!range.contains(hands[2]));
+        assert_eq!(range.hands().len(), 3);
+        assert_eq!(Range::full().combos(), 1326);
+        assert_eq!(Range::full().percent(), 100.0);
+    }
+
+    #[test]
+    fn test_chart() {
+        let range = Range::from_hands(&[
+            "AA".parse().unwrap(),
+            "AKo".parse().unwrap(),
+            "22".parse().unwrap(),
+        ]);
+        let chart = range.to_string();
+        let lines = chart.lines().collect::<Vec<_>>();
+
+        assert_eq!(lines.len(), 13);
+        assert!(lines[0].starts_with("AA  -   -"));
+        assert!(lines[1].starts_with("AKo -"));
+        assert!(lines[12].ends_with("22"));
+    }
+}
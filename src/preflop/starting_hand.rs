@@ -0,0 +1,243 @@
+use std::fmt;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::str::FromStr;
+
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::card::Rank;
+use crate::card::Suit;
+use crate::game::Hand;
+use crate::game::HandOf2;
+use crate::preflop::StartingHand;
+
+impl StartingHand {
+    pub fn new(high: Rank, low: Rank, suited: bool) -> Result<Self, Error> {
+        if high < low {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "ranks have to be in descending order",
+            ));
+        }
+
+        if high == low && suited {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "pairs cannot be suited",
+            ));
+        }
+
+        Ok(Self { high, low, suited })
+    }
+
+    pub fn from_hand(hand: HandOf2) -> Self {
+        let [a, b] = match hand.cards().as_slice() {
+            [a, b] => [*a, *b],
+            _ => unreachable!(),
+        };
+
+        Self {
+            high: a.rank().max(b.rank()),
+            low: a.rank().min(b.rank()),
+            suited: a.suit() == b.suit(),
+        }
+    }
+
+    // Hands are laid out on the usual 13x13 grid: pairs on the diagonal,
+    // suited hands above it and offsuit hands below it, aces first.
+    pub fn from_index(index: usize) -> Self {
+        let rank = |n: usize| Rank::list()[12 - n];
+        let (row, col) = (index / 13, index % 13);
+
+        Self {
+            high: rank(row.min(col)),
+            low: rank(row.max(col)),
+            suited: col > row,
+        }
+    }
+
+    pub fn all() -> Vec<Self> {
+        (0..169).map(Self::from_index).collect()
+    }
+
+    pub fn index(&self) -> usize {
+        let high = 12 - self.high as usize;
+        let low = 12 - self.low as usize;
+
+        if self.suited {
+            high * 13 + low
+        } else {
+            low * 13 + high
+        }
+    }
+
+    pub fn high(&self) -> Rank {
+        self.high
+    }
+
+    pub fn low(&self) -> Rank {
+        self.low
+    }
+
+    pub fn is_suited(&self) -> bool {
+        self.suited
+    }
+
+    pub fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    pub fn hands(&self) -> Vec<HandOf2> {
+        Suit::list()
+            .into_iter()
+            .cartesian_product(Suit::list())
+            .filter(|(a, b)| {
+                if self.is_pair() {
+                    a.precedence() < b.precedence()
+                } else {
+                    (a == b) == self.suited
+                }
+            })
+            .map(|(a, b)| HandOf2::new([Card(self.high, a), Card(self.low, b)]))
+            .collect()
+    }
+
+    pub fn combos(&self) -> usize {
+        match (self.is_pair(), self.suited) {
+            (true, _) => 6,
+            (false, true) => 4,
+            (false, false) => 12,
+        }
+    }
+
+    pub(crate) fn rank_char(rank: Rank) -> char {
+        "23456789TJQKA".chars().nth(rank as usize).unwrap()
+    }
+
+    fn char_rank(c: char) -> Option<Rank> {
+        "23456789TJQKA"
+            .chars()
+            .position(|r| r == c)
+            .map(|n| Rank::list()[n])
+    }
+}
+
+impl fmt::Display for StartingHand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let suffix = match (self.is_pair(), self.suited) {
+            (true, _) => "",
+            (false, true) => "s",
+            (false, false) => "o",
+        };
+
+        write!(
+            f,
+            "{}{}{}",
+            Self::rank_char(self.high),
+            Self::rank_char(self.low),
+            suffix
+        )
+    }
+}
+
+impl FromStr for StartingHand {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || Error::new(ErrorKind::InvalidInput, "invalid starting hand");
+        let chars = s.chars().collect::<Vec<_>>();
+
+        let (high, low) = match chars.as_slice() {
+            [high, low] | [high, low, _] => (
+                Self::char_rank(*high).ok_or_else(invalid)?,
+                Self::char_rank(*low).ok_or_else(invalid)?,
+            ),
+            _ => return Err(invalid()),
+        };
+
+        match chars.get(2) {
+            None if high == low => Self::new(high, low, false),
+            Some('s') => Self::new(high, low, true),
+            Some('o') if high != low => Self::new(high, low, false),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::convert::TryFrom;
+
+    use ::claim::*;
+
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::game::Hand;
+    use crate::game::HandOf2;
+    use crate::preflop::StartingHand;
+
+    #[test]
+    fn test_index() {
+        assert_eq!(StartingHand::all().len(), 169);
+        assert_eq!(
+            StartingHand::all()
+                .iter()
+                .map(|hand| hand.index())
+                .collect::<Vec<_>>(),
+            (0..169).collect::<Vec<_>>()
+        );
+        assert_eq!("AA".parse::<StartingHand>().unwrap().index(), 0);
+        assert_eq!("AKs".parse::<StartingHand>().unwrap().index(), 1);
+        assert_eq!("AKo".parse::<StartingHand>().unwrap().index(), 13);
+        assert_eq!("22".parse::<StartingHand>().unwrap().index(), 168);
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            "T9s".parse::<StartingHand>().unwrap(),
+            StartingHand::new(Rank::Ten, Rank::Nine, true).unwrap()
+        );
+        assert_eq!("72o".parse::<StartingHand>().unwrap().to_string(), "72o");
+        assert_err!("AAs".parse::<StartingHand>());
+        assert_err!("KA".parse::<StartingHand>());
+        assert_err!("AK".parse::<StartingHand>());
+        assert_err!("A1s".parse::<StartingHand>());
+    }
+
+    #[test]
+    fn test_hands() {
+        let total = StartingHand::all()
+            .iter()
+            .map(|hand| {
+                assert_eq!(hand.hands().len(), hand.combos());
+                hand.hands().len()
+            })
+            .sum::<usize>();
+
+        assert_eq!(total, 1326);
+        assert_eq!(
+            StartingHand::all()
+                .iter()
+                .flat_map(|hand| hand.hands())
+                .map(|hand| hand.cards())
+                .collect::<HashSet<_>>()
+                .len(),
+            1326
+        );
+    }
+
+    #[test]
+    fn test_from_hand() {
+        let hand = HandOf2::try_from(vec![
+            Card(Rank::Nine, Suit::Hearts),
+            Card(Rank::Jack, Suit::Hearts),
+        ])
+        .unwrap();
+
+        assert_eq!(StartingHand::from_hand(hand).to_string(), "J9s");
+    }
+}
@@ -0,0 +1,1465 @@
+use std::convert::TryFrom;
+use std::io::Error;
+use std::io::ErrorKind;
+
+use crate::card::Card;
+use crate::card::Deck;
+use crate::combination::Combination;
+use crate::game::Game;
+use crate::game::Hand;
+use crate::game::HandOf2;
+use crate::table::Action;
+use crate::table::Ante;
+use crate::table::Config;
+use crate::table::Event;
+use crate::table::Legal;
+use crate::table::Pot;
+use crate::table::Rake;
+use crate::table::Returning;
+use crate::table::Seat;
+use crate::table::Straddle;
+use crate::table::Street;
+use crate::table::Table;
+
+impl Seat {
+    pub fn stack(&self) -> u64 {
+        self.stack
+    }
+
+    pub fn hand(&self) -> HandOf2 {
+        self.hand
+    }
+
+    pub fn bet(&self) -> u64 {
+        self.bet
+    }
+
+    pub fn committed(&self) -> u64 {
+        self.committed
+    }
+
+    pub fn is_folded(&self) -> bool {
+        self.folded
+    }
+
+    pub fn is_all_in(&self) -> bool {
+        !self.folded && self.stack == 0
+    }
+
+    fn can_act(&self) -> bool {
+        !self.folded && self.stack > 0
+    }
+}
+
+impl Table {
+    pub fn new(
+        config: Config,
+        stacks: Vec<u64>,
+        button: usize,
+        mut deck: Deck,
+    ) -> Result<Self, Error> {
+        if stacks.len() < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "at least two players are required",
+            ));
+        }
+
+        if button >= stacks.len() {
+            return Err(Error::new(ErrorKind::InvalidInput, "no such seat"));
+        }
+
+        if stacks.contains(&0) {
+            return Err(Error::new(ErrorKind::InvalidInput, "empty stack"));
+        }
+
+        if config.straddle.is_some() && stacks.len() < 3 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "straddles require at least three players",
+            ));
+        }
+
+        let players = stacks.len();
+
+        // Two hole cards each, then five board cards behind three burns.
+        if deck.len() < players * 2 + 8 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "not enough cards for every player",
+            ));
+        }
+
+        let start = Event::Start {
+            config,
+            stacks: stacks.clone(),
+            button,
+            seed: deck.seed(),
+        };
+        let mut hands = vec![vec![]; players];
+
+        for _ in 0..2 {
+            for n in 1..=players {
+                hands[(button + n) % players].extend(deck.deal(1).unwrap());
+            }
+        }
+
+        let seats = stacks
+            .into_iter()
+            .zip(hands)
+            .map(|(stack, hand)| Seat {
+                stack,
+                hand: HandOf2::try_from(hand).unwrap(),
+                bet: 0,
+                committed: 0,
+                dead: 0,
+                folded: false,
+                acted: None,
+                posted: false,
+            })
+            .collect();
+
+        let mut table = Self {
+            config,
+            seats,
+            button,
+            deck,
+            burned: vec![],
+            board: vec![],
+            runouts: vec![],
+            locked: None,
+            street: Street::Preflop,
+            to_act: None,
+            current_bet: config.big_blind,
+            full_bet: config.big_blind,
+            last_raise: config.big_blind,
+            bets: 1,
+            aggressor: None,
+            log: vec![start],
+            awarded: false,
+        };
+
+        for seat in 0..players {
+            let cards = table.seats[seat].hand.cards();
+
+            table.log.push(Event::Deal { seat, cards });
+        }
+
+        let (small_blind, big_blind) = table.blind_seats();
+
+        table.post_blind(small_blind, config.small_blind);
+        table.post_blind(big_blind, config.big_blind);
+
+        // A straddle is a blind raise, so action starts to its left and
+        // the straddler acts last. A short straddle only raises to what was
+        // actually posted and does not count as a full raise.
+        let last_blind = match config.straddle {
+            Some(straddle) => {
+                let seat = match straddle {
+                    Straddle::Utg => (big_blind + 1) % players,
+                    Straddle::Mississippi => button,
+                };
+
+                table.post_blind(seat, config.big_blind * 2);
+
+                let posted = table.seats[seat].bet;
+
+                table.current_bet = table.current_bet.max(posted);
+
+                if posted == config.big_blind * 2 {
+                    table.full_bet = posted;
+                    table.last_raise = posted;
+                    table.bets = 2;
+                }
+
+                seat
+            }
+            None => big_blind,
+        };
+
+        // Blinds take priority over antes when a stack cannot cover both.
+        match config.ante {
+            Some(Ante::Classic(ante)) => (0..players).for_each(|seat| table.post_dead(seat, ante)),
+            Some(Ante::BigBlind(ante)) => table.post_dead(big_blind, ante),
+            Some(Ante::Button(ante)) => table.post_dead(button, ante),
+            None => (),
+        }
+
+        table.to_act = table.next_to_act(last_blind);
+
+        if table.to_act.is_none() {
+            table.finish_street();
+        }
+
+        Ok(table)
+    }
+
+    pub fn config(&self) -> Config {
+        self.config
+    }
+
+    pub fn seats(&self) -> Vec<Seat> {
+        self.seats.clone()
+    }
+
+    pub fn button(&self) -> usize {
+        self.button
+    }
+
+    pub fn board(&self) -> Vec<Card> {
+        self.board.clone()
+    }
+
+    pub fn boards(&self) -> Vec<Vec<Card>> {
+        std::iter::once(self.board.clone())
+            .chain(self.runouts.iter().cloned())
+            .collect()
+    }
+
+    pub fn street(&self) -> Street {
+        self.street
+    }
+
+    pub fn to_act(&self) -> Option<usize> {
+        self.to_act
+    }
+
+    pub fn current_bet(&self) -> u64 {
+        self.current_bet
+    }
+
+    pub fn pot(&self) -> u64 {
+        self.seats.iter().map(|seat| seat.committed).sum()
+    }
+
+    pub fn contenders(&self) -> Vec<usize> {
+        (0..self.seats.len())
+            .filter(|seat| !self.seats[*seat].folded)
+            .collect()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.to_act.is_none()
+    }
+
+    pub fn act(&mut self, action: Action) -> Result<(), Error> {
+        let seat = self
+            .to_act
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "hand is complete"))?;
+
+        if self.street == Street::Showdown {
+            return self.show_or_muck(seat, action);
+        }
+
+        let bet = self.seats[seat].bet;
+        let stack = self.seats[seat].stack;
+        let to_call = self.current_bet - bet;
+
+        match action {
+            Action::Fold => self.seats[seat].folded = true,
+            Action::Check => {
+                if to_call > 0 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "cannot check facing a bet",
+                    ));
+                }
+            }
+            Action::Call => {
+                if to_call == 0 {
+                    return Err(Error::new(ErrorKind::InvalidInput, "nothing to call"));
+                }
+
+                self.post(seat, to_call);
+            }
+            Action::Bet(amount) => {
+                if self.current_bet > 0 {
+                    return Err(Error::new(
+                        ErrorKind::InvalidInput,
+                        "cannot bet facing a bet",
+                    ));
+                }
+
+                self.check_raise(seat, amount)?;
+                self.raise_to(seat, amount);
+            }
+            Action::Raise(to) => {
+                if self.current_bet == 0 {
+                    return Err(Error::new(ErrorKind::InvalidInput, "nothing to raise"));
+                }
+
+                self.check_raise(seat, to)?;
+                self.raise_to(seat, to);
+            }
+            Action::AllIn => {
+                if bet + stack <= self.current_bet {
+                    self.post(seat, stack);
+                } else {
+                    self.check_raise(seat, bet + stack)?;
+                    self.raise_to(seat, bet + stack);
+                }
+            }
+            Action::Show | Action::Muck => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "hands are only shown at showdown",
+                ));
+            }
+        }
+
+        self.seats[seat].acted = Some(self.full_bet);
+        self.log.push(Event::Act { seat, action });
+        self.advance(seat);
+
+        Ok(())
+    }
+
+    pub fn post_returning(&mut self, seat: usize, returning: Returning) -> Result<(), Error> {
+        if seat >= self.seats.len() {
+            return Err(Error::new(ErrorKind::InvalidInput, "no such seat"));
+        }
+
+        if self.street != Street::Preflop || self.seats.iter().any(|seat| seat.acted.is_some()) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "blinds have to be posted before any action",
+            ));
+        }
+
+        if self.seats[seat].posted {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "seat has already posted",
+            ));
+        }
+
+        self.log.push(Event::Return { seat, returning });
+
+        if returning == Returning::Dead {
+            self.post_dead(seat, self.config.small_blind);
+        }
+
+        self.post_blind(seat, self.config.big_blind);
+
+        // A short post can leave the seat that was due to act all-in.
+        if let Some(to_act) = self.to_act {
+            if !self.needs_to_act(to_act) {
+                self.advance(to_act);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn legal_actions(&self) -> Vec<Legal> {
+        let seat = match self.to_act {
+            Some(seat) => seat,
+            None => return vec![],
+        };
+
+        if self.street == Street::Showdown {
+            return vec![Legal::Show, Legal::Muck];
+        }
+
+        let bet = self.seats[seat].bet;
+        let stack = self.seats[seat].stack;
+        let to_call = self.current_bet - bet;
+        let bounds = self.config.structure.raise_bounds(self, seat);
+
+        let mut actions = vec![Legal::Fold];
+
+        if to_call == 0 {
+            actions.push(Legal::Check);
+        } else {
+            actions.push(Legal::Call(to_call.min(stack)));
+        }
+
+        match bounds {
+            Some((min, max)) if min < bet + stack => {
+                if self.current_bet == 0 {
+                    actions.push(Legal::Bet { min, max });
+                } else {
+                    actions.push(Legal::Raise { min, max });
+                }
+            }
+            _ => (),
+        }
+
+        if bet + stack <= self.current_bet || bounds.is_some_and(|(_, max)| max == bet + stack) {
+            actions.push(Legal::AllIn(bet + stack));
+        }
+
+        actions
+    }
+
+    pub fn showdown(&self) -> Result<Vec<Vec<Vec<Card>>>, Error> {
+        if self.street != Street::Showdown {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "hand is not at showdown",
+            ));
+        }
+
+        if self.to_act.is_some() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "players still have to show or muck",
+            ));
+        }
+
+        Ok(self.showdown_on(self.board.clone()))
+    }
+
+    pub fn run_it(&mut self, times: usize) -> Result<Vec<Vec<Card>>, Error> {
+        let locked = match self.locked {
+            Some(locked) if self.street == Street::Showdown => locked,
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "board was not run out with players all-in",
+                ))
+            }
+        };
+
+        if times == 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "board has to be run at least once",
+            ));
+        }
+
+        if !self.runouts.is_empty() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "board has already been run",
+            ));
+        }
+
+        let burns = match locked {
+            0 => 3,
+            3 => 2,
+            _ => 1,
+        };
+
+        if self.deck.len() < (5 - locked + burns) * (times - 1) {
+            return Err(Error::new(ErrorKind::InvalidInput, "not enough cards"));
+        }
+
+        for _ in 1..times {
+            let mut board = self.board[..locked].to_vec();
+
+            while board.len() < 5 {
+                let n = if board.is_empty() { 3 } else { 1 };
+
+                self.burned.extend(self.deck.deal(1).unwrap());
+                board.extend(self.deck.deal(n).unwrap());
+            }
+
+            self.runouts.push(board);
+        }
+
+        self.log.push(Event::RunIt {
+            boards: self.runouts.clone(),
+        });
+
+        Ok(self.boards())
+    }
+
+    pub fn pots(&self) -> Vec<Pot> {
+        let (contributions, folded) = self.contributions();
+        let dead = self.seats.iter().map(|seat| seat.dead).collect::<Vec<_>>();
+
+        Pot::build(&contributions, &dead, &folded)
+    }
+
+    pub fn settle(&self) -> Result<Vec<i64>, Error> {
+        self.settle_pots(self.pots())
+    }
+
+    pub fn settle_with_rake(&self, rake: &Rake) -> Result<(Vec<i64>, Vec<u64>), Error> {
+        let street = match self.board.len() {
+            0 => Street::Preflop,
+            3 => Street::Flop,
+            4 => Street::Turn,
+            _ => Street::River,
+        };
+
+        let pots = self.pots();
+        let taken = rake.take(&pots, self.seats.len(), street);
+
+        let raked = pots
+            .into_iter()
+            .zip(taken.iter())
+            .map(|(pot, rake)| Pot {
+                amount: pot.amount - rake,
+                eligible: pot.eligible,
+            })
+            .collect();
+
+        Ok((self.settle_pots(raked)?, taken))
+    }
+
+    fn settle_pots(&self, pots: Vec<Pot>) -> Result<Vec<i64>, Error> {
+        let contenders = self.contenders();
+        let hands = self
+            .seats
+            .iter()
+            .map(|seat| seat.hand.cards())
+            .collect::<Vec<_>>();
+
+        let runs = if contenders.len() == 1 {
+            vec![vec![contenders]]
+        } else {
+            self.showdown()?;
+
+            self.boards()
+                .into_iter()
+                .map(|board| Pot::places(self.showdown_on(board), &hands))
+                .collect()
+        };
+
+        let players = self.seats.len();
+        let order = (1..=players)
+            .map(|n| (self.button + n) % players)
+            .collect::<Vec<_>>();
+
+        let times = runs.len() as u64;
+        let mut winnings = vec![0; players];
+
+        // Each pot is split evenly across the runouts, with odd chips going
+        // to the earlier runs.
+        for (n, places) in runs.iter().enumerate() {
+            let run_pots = pots
+                .iter()
+                .map(|pot| Pot {
+                    amount: pot.amount / times
+                        + if (n as u64) < pot.amount % times {
+                            1
+                        } else {
+                            0
+                        },
+                    eligible: pot.eligible.clone(),
+                })
+                .collect::<Vec<_>>();
+
+            Pot::award(&run_pots, places, self.config.odd_chip, &order, &hands)
+                .into_iter()
+                .enumerate()
+                .for_each(|(seat, won)| winnings[seat] += won);
+        }
+
+        let (contributions, _) = self.contributions();
+
+        Ok(Pot::ledger(&contributions, &winnings))
+    }
+
+    // Hands are ranked by full strength so that kickers decide the pot.
+    fn showdown_on(&self, board: Vec<Card>) -> Vec<Vec<Vec<Card>>> {
+        Game::sort_hands(Game::group_hands(
+            self.contenders()
+                .into_iter()
+                .map(|seat| self.seats[seat].hand.cards())
+                .map(|hand| {
+                    let strength = Combination::strength(&[&hand[..], &board[..]].concat());
+
+                    (hand, strength)
+                })
+                .collect(),
+        ))
+    }
+
+    fn contributions(&self) -> (Vec<u64>, Vec<bool>) {
+        self.seats
+            .iter()
+            .map(|seat| (seat.committed, seat.folded))
+            .unzip()
+    }
+
+    fn blind_seats(&self) -> (usize, usize) {
+        let players = self.seats.len();
+
+        if players == 2 {
+            (self.button, (self.button + 1) % players)
+        } else {
+            ((self.button + 1) % players, (self.button + 2) % players)
+        }
+    }
+
+    fn post(&mut self, seat: usize, amount: u64) {
+        let seat = &mut self.seats[seat];
+        let amount = amount.min(seat.stack);
+
+        seat.stack -= amount;
+        seat.bet += amount;
+        seat.committed += amount;
+    }
+
+    fn post_blind(&mut self, seat: usize, amount: u64) {
+        let amount = amount.min(self.seats[seat].stack);
+
+        self.post(seat, amount);
+        self.seats[seat].posted = true;
+        self.log.push(Event::Post {
+            seat,
+            amount,
+            dead: false,
+        });
+    }
+
+    fn post_dead(&mut self, seat: usize, amount: u64) {
+        let amount = amount.min(self.seats[seat].stack);
+
+        self.seats[seat].stack -= amount;
+        self.seats[seat].committed += amount;
+        self.seats[seat].dead += amount;
+        self.log.push(Event::Post {
+            seat,
+            amount,
+            dead: true,
+        });
+    }
+
+    fn check_raise(&self, seat: usize, to: u64) -> Result<(), Error> {
+        if to <= self.current_bet {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "bet has to exceed the current bet",
+            ));
+        }
+
+        if to > self.seats[seat].bet + self.seats[seat].stack {
+            return Err(Error::new(ErrorKind::InvalidInput, "not enough chips"));
+        }
+
+        let (min, max) = self
+            .config
+            .structure
+            .raise_bounds(self, seat)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "raising is not allowed"))?;
+
+        if to < min {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "bet is below the minimum",
+            ));
+        }
+
+        if to > max {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "bet is above the maximum",
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn raise_to(&mut self, seat: usize, to: u64) {
+        let increment = to - self.current_bet;
+
+        self.post(seat, to - self.seats[seat].bet);
+        self.aggressor = Some(seat);
+
+        if increment >= self.last_raise {
+            self.last_raise = increment;
+            self.full_bet = to;
+            self.bets += 1;
+        }
+
+        self.current_bet = to;
+    }
+
+    fn needs_to_act(&self, seat: usize) -> bool {
+        let others = (0..self.seats.len())
+            .filter(|other| *other != seat && self.seats[*other].can_act())
+            .count();
+
+        let seat = &self.seats[seat];
+
+        seat.can_act() && (seat.bet < self.current_bet || (seat.acted.is_none() && others > 0))
+    }
+
+    fn next_to_act(&self, from: usize) -> Option<usize> {
+        let order = self.action_order();
+        let players = order.len();
+        let position = order.iter().position(|seat| *seat == from).unwrap();
+
+        (1..=players)
+            .map(|n| order[(position + n) % players])
+            .find(|seat| self.needs_to_act(*seat))
+    }
+
+    // Seats in the order they act, starting left of the button. A
+    // Mississippi straddler acts after the big blind preflop, so action
+    // still opens under the gun and the straddler closes it.
+    fn action_order(&self) -> Vec<usize> {
+        let players = self.seats.len();
+        let mut order = (1..=players)
+            .map(|n| (self.button + n) % players)
+            .collect::<Vec<_>>();
+
+        if self.street == Street::Preflop && self.config.straddle == Some(Straddle::Mississippi) {
+            let (_, big_blind) = self.blind_seats();
+            let button = order.pop().unwrap();
+            let after = order.iter().position(|seat| *seat == big_blind).unwrap();
+
+            order.insert(after + 1, button);
+        }
+
+        order
+    }
+
+    fn advance(&mut self, from: usize) {
+        if self.contenders().len() == 1 {
+            self.to_act = None;
+            self.return_uncalled();
+        } else {
+            self.to_act = self.next_to_act(from);
+
+            if self.to_act.is_none() {
+                self.finish_street();
+            }
+        }
+    }
+
+    fn finish_street(&mut self) {
+        loop {
+            let dealt = self.board.len();
+
+            self.seats.iter_mut().for_each(|seat| {
+                seat.bet = 0;
+                seat.acted = None;
+            });
+
+            self.street = match self.street {
+                Street::Preflop => Street::Flop,
+                Street::Flop => Street::Turn,
+                Street::Turn => Street::River,
+                Street::River | Street::Showdown => Street::Showdown,
+            };
+
+            self.current_bet = 0;
+            self.full_bet = 0;
+            self.last_raise = self
+                .config
+                .structure
+                .bet_size(self.street, self.config.big_blind);
+            self.bets = 0;
+
+            if self.street != Street::Showdown {
+                self.aggressor = None;
+            }
+
+            match self.street {
+                Street::Flop => self.burn_and_deal(3),
+                Street::Turn | Street::River => self.burn_and_deal(1),
+                Street::Preflop | Street::Showdown => {
+                    self.log.push(Event::Street {
+                        street: self.street,
+                        cards: vec![],
+                    });
+
+                    self.to_act = None;
+                    self.return_uncalled();
+                    self.reveal();
+                    return;
+                }
+            }
+
+            self.to_act = self.next_to_act(self.button);
+
+            if self.to_act.is_some() {
+                return;
+            }
+
+            self.locked = self.locked.or(Some(dealt));
+        }
+    }
+
+    fn burn_and_deal(&mut self, n: usize) {
+        let cards = self.deck.deal(n + 1).unwrap();
+
+        self.burned.push(cards[0]);
+        self.board.extend(cards[1..].iter().copied());
+        self.log.push(Event::Street {
+            street: self.street,
+            cards: cards[1..].to_vec(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use ::claim::*;
+
+    use crate::card::Card;
+    use crate::card::Deck;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::table::Action;
+    use crate::table::Ante;
+    use crate::table::Config;
+    use crate::table::Legal;
+    use crate::table::Rake;
+    use crate::table::Returning;
+    use crate::table::Straddle;
+    use crate::table::Street;
+    use crate::table::Structure;
+    use crate::table::Table;
+
+    fn table(stacks: Vec<u64>) -> Table {
+        Table::new(Config::new(1, 2), stacks, 0, Deck::new(0)).unwrap()
+    }
+
+    fn table_with(structure: Structure, stacks: Vec<u64>) -> Table {
+        let config = Config {
+            structure,
+            ..Config::new(1, 2)
+        };
+
+        Table::new(config, stacks, 0, Deck::new(0)).unwrap()
+    }
+
+    #[test]
+    fn test_new() {
+        let table = table(vec![100, 100, 100, 100]);
+
+        assert_eq!(table.street(), Street::Preflop);
+        assert_eq!(
+            table
+                .seats()
+                .iter()
+                .map(|seat| (seat.stack(), seat.bet()))
+                .collect::<Vec<_>>(),
+            vec![(100, 0), (99, 1), (98, 2), (100, 0)]
+        );
+        assert_eq!(table.to_act(), Some(3));
+        assert_eq!(table.pot(), 3);
+    }
+
+    #[test]
+    fn test_new_invalid() {
+        assert_err!(Table::new(Config::new(1, 2), vec![100], 0, Deck::new(0)));
+        assert_err!(Table::new(
+            Config::new(1, 2),
+            vec![100, 100],
+            2,
+            Deck::new(0)
+        ));
+        assert_err!(Table::new(Config::new(1, 2), vec![100, 0], 0, Deck::new(0)));
+        assert_err!(Table::new(
+            Config::new(1, 2),
+            vec![100; 23],
+            0,
+            Deck::new(0)
+        ));
+    }
+
+    #[test]
+    fn test_full_ring_runs_out() {
+        let mut table = table(vec![100; 22]);
+
+        for _ in 0..22 {
+            assert_ok!(table.act(Action::AllIn));
+        }
+
+        assert_eq!(table.street(), Street::Showdown);
+        assert_eq!(table.board().len(), 5);
+    }
+
+    #[test]
+    fn test_heads_up_order() {
+        let mut table = table(vec![100, 100]);
+
+        assert_eq!(table.seats()[0].bet(), 1);
+        assert_eq!(table.seats()[1].bet(), 2);
+        assert_eq!(table.to_act(), Some(0));
+
+        assert_ok!(table.act(Action::Call));
+        assert_eq!(table.to_act(), Some(1));
+        assert_ok!(table.act(Action::Check));
+
+        assert_eq!(table.street(), Street::Flop);
+        assert_eq!(table.to_act(), Some(1));
+    }
+
+    #[test]
+    fn test_check_down_to_showdown() {
+        let mut table = table(vec![100, 100, 100]);
+
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Check));
+
+        for street in [Street::Flop, Street::Turn, Street::River].iter() {
+            assert_eq!(table.street(), *street);
+            assert_eq!(table.to_act(), Some(1));
+
+            for _ in 0..3 {
+                assert_ok!(table.act(Action::Check));
+            }
+        }
+
+        assert_eq!(table.street(), Street::Showdown);
+        assert_eq!(table.board().len(), 5);
+        assert_eq!(table.revealed(), vec![1, 2]);
+        assert_eq!(table.to_act(), Some(0));
+        assert_eq!(table.legal_actions(), vec![Legal::Show, Legal::Muck]);
+        assert_err!(table.showdown());
+
+        assert_ok!(table.act(Action::Show));
+
+        assert!(table.is_complete());
+        assert_eq!(table.pot(), 6);
+        assert_eq!(table.showdown().unwrap().concat().len(), 3);
+    }
+
+    #[test]
+    fn test_big_blind_option() {
+        let mut table = table(vec![100, 100, 100]);
+
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Call));
+        assert_eq!(table.to_act(), Some(2));
+        assert_ok!(table.act(Action::Raise(6)));
+        assert_eq!(table.to_act(), Some(0));
+        assert_eq!(table.street(), Street::Preflop);
+    }
+
+    #[test]
+    fn test_everyone_folds() {
+        let mut table = table(vec![100, 100, 100]);
+
+        assert_ok!(table.act(Action::Fold));
+        assert_ok!(table.act(Action::Fold));
+
+        assert!(table.is_complete());
+        assert_eq!(table.contenders(), vec![2]);
+        assert_err!(table.showdown());
+        assert_err!(table.act(Action::Check));
+    }
+
+    #[test]
+    fn test_illegal_actions() {
+        let mut table = table(vec![100, 100, 100]);
+
+        assert_err!(table.act(Action::Check));
+        assert_err!(table.act(Action::Bet(10)));
+        assert_err!(table.act(Action::Raise(3)));
+        assert_err!(table.act(Action::Raise(101)));
+        assert_ok!(table.act(Action::Raise(4)));
+        assert_err!(table.act(Action::Raise(5)));
+        assert_ok!(table.act(Action::Raise(6)));
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Call));
+
+        assert_eq!(table.street(), Street::Flop);
+        assert_err!(table.act(Action::Call));
+        assert_err!(table.act(Action::Raise(10)));
+        assert_err!(table.act(Action::Bet(1)));
+        assert_ok!(table.act(Action::Bet(2)));
+    }
+
+    #[test]
+    fn test_incomplete_raise_does_not_reopen() {
+        let mut table = table(vec![100, 100, 100, 13]);
+
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Raise(10)));
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::AllIn));
+
+        assert_eq!(table.current_bet(), 13);
+        assert_eq!(table.to_act(), Some(0));
+        assert_err!(table.act(Action::Raise(30)));
+        assert_ok!(table.act(Action::Call));
+    }
+
+    #[test]
+    fn test_all_in_runs_out_the_board() {
+        let mut table = table(vec![50, 100, 100]);
+
+        assert_ok!(table.act(Action::AllIn));
+        assert_ok!(table.act(Action::AllIn));
+        assert_ok!(table.act(Action::Fold));
+
+        assert_eq!(table.street(), Street::Showdown);
+        assert_eq!(table.board().len(), 5);
+        assert_eq!(table.pot(), 102);
+        assert!(table.seats()[0].is_all_in());
+        assert_eq!(table.seats()[1].stack(), 50);
+        assert_eq!(table.revealed().len(), 2);
+
+        let pots = table.pots();
+
+        assert_eq!(pots.len(), 1);
+        assert_eq!(pots[0].amount(), 102);
+        assert_eq!(pots[0].eligible(), vec![0, 1]);
+
+        let ledger = table.settle().unwrap();
+
+        assert_eq!(ledger[2], -2);
+        assert_eq!(ledger.iter().sum::<i64>(), 0);
+    }
+
+    #[test]
+    fn test_settle_before_showdown() {
+        let mut table = table(vec![100, 100, 100]);
+
+        assert_err!(table.settle());
+        assert_ok!(table.act(Action::Raise(6)));
+        assert_ok!(table.act(Action::Fold));
+        assert_ok!(table.act(Action::Fold));
+
+        assert_eq!(table.settle().unwrap(), vec![3, -1, -2]);
+    }
+
+    #[test]
+    fn test_legal_actions_no_limit() {
+        let table = table(vec![100, 100, 100]);
+
+        assert_eq!(
+            table.legal_actions(),
+            vec![
+                Legal::Fold,
+                Legal::Call(2),
+                Legal::Raise { min: 4, max: 100 },
+                Legal::AllIn(100)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_pot_limit() {
+        let mut table = table_with(Structure::PotLimit, vec![100, 100, 100, 100]);
+
+        assert!(table
+            .legal_actions()
+            .contains(&Legal::Raise { min: 4, max: 7 }));
+        assert_err!(table.act(Action::Raise(8)));
+        assert_err!(table.act(Action::AllIn));
+        assert_ok!(table.act(Action::Raise(7)));
+
+        assert_eq!(
+            table.legal_actions(),
+            vec![
+                Legal::Fold,
+                Legal::Call(7),
+                Legal::Raise { min: 12, max: 24 }
+            ]
+        );
+        assert_ok!(table.act(Action::Raise(24)));
+    }
+
+    #[test]
+    fn test_fixed_limit_cap() {
+        let mut table = table_with(Structure::FixedLimit { cap: 4 }, vec![100, 100, 100]);
+
+        assert_err!(table.act(Action::Raise(5)));
+        assert_ok!(table.act(Action::Raise(4)));
+        assert_ok!(table.act(Action::Raise(6)));
+        assert_ok!(table.act(Action::Raise(8)));
+
+        assert_eq!(table.legal_actions(), vec![Legal::Fold, Legal::Call(4)]);
+        assert_err!(table.act(Action::Raise(10)));
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Call));
+
+        assert_eq!(table.street(), Street::Flop);
+        assert_ok!(table.act(Action::Bet(2)));
+        assert_eq!(
+            Structure::FixedLimit { cap: 4 }.bet_size(Street::Turn, 2),
+            4
+        );
+    }
+
+    #[test]
+    fn test_fixed_limit_heads_up_uncapped() {
+        let mut table = table_with(Structure::FixedLimit { cap: 4 }, vec![100, 100]);
+
+        assert_ok!(table.act(Action::Raise(4)));
+        assert_ok!(table.act(Action::Raise(6)));
+        assert_ok!(table.act(Action::Raise(8)));
+        assert_ok!(table.act(Action::Raise(10)));
+        assert_ok!(table.act(Action::Raise(12)));
+    }
+
+    #[test]
+    fn test_spread_limit() {
+        let mut table = table_with(
+            Structure::SpreadLimit { min: 2, max: 10 },
+            vec![100, 100, 100],
+        );
+
+        assert!(table
+            .legal_actions()
+            .contains(&Legal::Raise { min: 4, max: 12 }));
+        assert_err!(table.act(Action::Raise(13)));
+        assert_ok!(table.act(Action::Raise(12)));
+    }
+
+    fn stacks(table: &Table) -> Vec<u64> {
+        table.seats().iter().map(|seat| seat.stack()).collect()
+    }
+
+    #[test]
+    fn test_antes() {
+        let antes = [
+            (Ante::Classic(1), vec![99, 98, 97]),
+            (Ante::BigBlind(3), vec![100, 99, 95]),
+            (Ante::Button(3), vec![97, 99, 98]),
+        ];
+
+        for (ante, expected) in antes.iter() {
+            let config = Config {
+                ante: Some(*ante),
+                ..Config::new(1, 2)
+            };
+            let table = Table::new(config, vec![100, 100, 100], 0, Deck::new(0)).unwrap();
+
+            assert_eq!(stacks(&table), *expected);
+            assert_eq!(table.pot(), 6);
+            assert_eq!(table.current_bet(), 2);
+            assert_eq!(table.to_act(), Some(0));
+        }
+    }
+
+    #[test]
+    fn test_utg_straddle() {
+        let config = Config {
+            straddle: Some(Straddle::Utg),
+            ..Config::new(1, 2)
+        };
+        let mut table = Table::new(config, vec![100, 100, 100, 100], 0, Deck::new(0)).unwrap();
+
+        assert_eq!(table.seats()[3].bet(), 4);
+        assert_eq!(table.current_bet(), 4);
+        assert_eq!(table.to_act(), Some(0));
+        assert!(table
+            .legal_actions()
+            .contains(&Legal::Raise { min: 8, max: 100 }));
+
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Call));
+
+        assert_eq!(table.to_act(), Some(3));
+        assert!(table.legal_actions().contains(&Legal::Check));
+    }
+
+    #[test]
+    fn test_mississippi_straddle() {
+        let config = Config {
+            straddle: Some(Straddle::Mississippi),
+            ..Config::new(1, 2)
+        };
+        let mut table = Table::new(config, vec![100, 100, 100, 100], 0, Deck::new(0)).unwrap();
+
+        assert_eq!(table.seats()[0].bet(), 4);
+
+        for seat in [3, 1, 2].iter() {
+            assert_eq!(table.to_act(), Some(*seat));
+            assert_ok!(table.act(Action::Call));
+        }
+
+        assert_eq!(table.to_act(), Some(0));
+        assert!(table.legal_actions().contains(&Legal::Check));
+        assert_ok!(table.act(Action::Check));
+
+        assert_eq!(table.street(), Street::Flop);
+        assert_eq!(table.to_act(), Some(1));
+        assert_err!(Table::new(config, vec![100, 100], 0, Deck::new(0)));
+    }
+
+    #[test]
+    fn test_post_returning() {
+        let mut table = table(vec![100, 100, 100, 100, 100]);
+
+        assert_err!(table.post_returning(1, Returning::Posted));
+        assert_err!(table.post_returning(5, Returning::Posted));
+        assert_ok!(table.post_returning(3, Returning::Dead));
+        assert_ok!(table.post_returning(4, Returning::Posted));
+
+        assert_eq!(stacks(&table), vec![100, 99, 98, 97, 98]);
+        assert_eq!(table.seats()[3].bet(), 2);
+        assert_eq!(table.pot(), 8);
+        assert_eq!(table.to_act(), Some(3));
+        assert!(table.legal_actions().contains(&Legal::Check));
+
+        assert_ok!(table.act(Action::Check));
+        assert_err!(table.post_returning(0, Returning::Posted));
+    }
+
+    #[test]
+    fn test_post_returning_short() {
+        let mut table = table(vec![100, 100, 100, 2]);
+
+        assert_eq!(table.to_act(), Some(3));
+        assert_ok!(table.post_returning(3, Returning::Dead));
+
+        assert_eq!(table.seats()[3].stack(), 0);
+        assert_eq!(table.seats()[3].bet(), 1);
+        assert_eq!(table.to_act(), Some(0));
+
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Check));
+
+        assert_eq!(table.street(), Street::Flop);
+    }
+
+    #[test]
+    fn test_post_returning_with_antes() {
+        let config = Config {
+            ante: Some(Ante::Classic(1)),
+            ..Config::new(1, 2)
+        };
+        let mut table = Table::new(config, vec![100, 100, 100, 100], 0, Deck::new(0)).unwrap();
+
+        assert_err!(table.post_returning(2, Returning::Posted));
+        assert_ok!(table.post_returning(3, Returning::Posted));
+        assert_err!(table.post_returning(3, Returning::Posted));
+        assert_eq!(stacks(&table), vec![99, 98, 97, 97]);
+    }
+
+    #[test]
+    fn test_blinds_before_antes() {
+        let config = Config {
+            ante: Some(Ante::Classic(1)),
+            ..Config::new(1, 2)
+        };
+        let table = Table::new(config, vec![100, 100, 2], 0, Deck::new(0)).unwrap();
+
+        assert_eq!(table.seats()[2].bet(), 2);
+        assert_eq!(stacks(&table), vec![99, 98, 0]);
+        assert_eq!(table.current_bet(), 2);
+    }
+
+    #[test]
+    fn test_short_straddle() {
+        let config = Config {
+            straddle: Some(Straddle::Utg),
+            ..Config::new(1, 2)
+        };
+        let table = Table::new(config, vec![100, 100, 100, 3], 0, Deck::new(0)).unwrap();
+
+        assert_eq!(table.seats()[3].bet(), 3);
+        assert_eq!(table.current_bet(), 3);
+        assert!(table.legal_actions().contains(&Legal::Call(3)));
+        assert!(table
+            .legal_actions()
+            .contains(&Legal::Raise { min: 5, max: 100 }));
+    }
+
+    #[test]
+    fn test_run_it_twice() {
+        let mut table = table(vec![50, 100, 100]);
+
+        assert_err!(table.run_it(2));
+        assert_ok!(table.act(Action::AllIn));
+        assert_ok!(table.act(Action::AllIn));
+        assert_ok!(table.act(Action::Fold));
+
+        assert_err!(table.run_it(0));
+
+        let boards = table.run_it(2).unwrap();
+
+        assert_eq!(boards.len(), 2);
+        assert_eq!(boards.concat().iter().collect::<HashSet<_>>().len(), 10);
+        assert_err!(table.run_it(2));
+
+        let ledger = table.settle().unwrap();
+
+        assert_eq!(ledger[2], -2);
+        assert_eq!(ledger.iter().sum::<i64>(), 0);
+    }
+
+    #[test]
+    fn test_run_it_on_the_river() {
+        let mut table = table(vec![100, 100]);
+
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Check));
+
+        for _ in 0..3 {
+            assert_ok!(table.act(Action::Check));
+            assert_ok!(table.act(Action::Check));
+        }
+
+        assert_eq!(table.street(), Street::Showdown);
+        assert_err!(table.run_it(2));
+    }
+
+    #[test]
+    fn test_settle_with_rake() {
+        let mut table = table(vec![100, 100, 100]);
+
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Check));
+
+        for _ in 0..3 {
+            for _ in 0..3 {
+                assert_ok!(table.act(Action::Check));
+            }
+        }
+
+        assert_ok!(table.act(Action::Show));
+
+        let rake = Rake {
+            caps: vec![(2, 1)],
+            ..Rake::new(5000)
+        };
+        let (ledger, taken) = table.settle_with_rake(&rake).unwrap();
+
+        assert_eq!(taken, vec![1]);
+        assert_eq!(ledger.iter().sum::<i64>(), -1);
+    }
+
+    #[test]
+    fn test_rake_without_showdown() {
+        let mut table = table(vec![100, 100, 100]);
+
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Call));
+        assert_ok!(table.act(Action::Check));
+
+        assert_ok!(table.act(Action::Bet(10)));
+        assert_ok!(table.act(Action::Fold));
+        assert_ok!(table.act(Action::Fold));
+
+        assert_eq!(table.board().len(), 3);
+
+        let (ledger, taken) = table.settle_with_rake(&Rake::new(5000)).unwrap();
+
+        assert_eq!(taken, vec![3]);
+        assert_eq!(ledger, vec![-2, 1, -2]);
+    }
+
+    #[test]
+    fn test_no_flop_no_drop() {
+        let mut table = table(vec![100, 100, 100]);
+
+        assert_ok!(table.act(Action::Raise(6)));
+        assert_ok!(table.act(Action::Fold));
+        assert_ok!(table.act(Action::Fold));
+
+        let (_, taken) = table.settle_with_rake(&Rake::new(5000)).unwrap();
+
+        assert_eq!(taken, vec![2]);
+
+        let rake = Rake {
+            no_flop_no_drop: true,
+            ..Rake::new(5000)
+        };
+        let (ledger, taken) = table.settle_with_rake(&rake).unwrap();
+
+        assert_eq!(taken.iter().sum::<u64>(), 0);
+        assert_eq!(ledger, vec![3, -1, -2]);
+    }
+
+    fn check_down(table: &mut Table) {
+        while table.street() != Street::Showdown {
+            let action = if table.legal_actions().contains(&Legal::Check) {
+                Action::Check
+            } else {
+                Action::Call
+            };
+
+            assert_ok!(table.act(action));
+        }
+
+        while !table.is_complete() {
+            assert_ok!(table.act(Action::Show));
+        }
+    }
+
+    #[test]
+    fn test_losing_ante_poster() {
+        let config = Config {
+            ante: Some(Ante::BigBlind(2)),
+            ..Config::new(1, 2)
+        };
+        let mut table = Table::new(config, vec![100, 100, 100], 0, Deck::new(3)).unwrap();
+
+        check_down(&mut table);
+
+        assert_eq!(table.pots().len(), 1);
+        assert_eq!(table.pots()[0].amount(), 8);
+        assert_eq!(table.settle().unwrap(), vec![-2, 6, -4]);
+    }
+
+    #[test]
+    fn test_losing_dead_blind_poster() {
+        let mut table =
+            Table::new(Config::new(1, 2), vec![100, 100, 100, 100], 0, Deck::new(1)).unwrap();
+
+        assert_ok!(table.post_returning(3, Returning::Dead));
+
+        check_down(&mut table);
+
+        assert_eq!(table.pots().len(), 1);
+        assert_eq!(table.pots()[0].amount(), 9);
+        assert_eq!(table.settle().unwrap(), vec![2, 3, -2, -3]);
+    }
+
+    #[test]
+    fn test_kicker_decides_the_pot() {
+        let mut table = Table::new(Config::new(1, 2), vec![100, 100], 0, Deck::new(17)).unwrap();
+
+        check_down(&mut table);
+
+        // Both play ace-queen-ten-nine high; the jack beats the six.
+        assert_eq!(
+            table.board(),
+            vec![
+                Card(Rank::Ten, Suit::Spades),
+                Card(Rank::Nine, Suit::Diamonds),
+                Card(Rank::Six, Suit::Clubs),
+                Card(Rank::Queen, Suit::Clubs),
+                Card(Rank::Ace, Suit::Spades),
+            ]
+        );
+        assert_eq!(
+            table.showdown().unwrap(),
+            vec![
+                vec![vec![
+                    Card(Rank::Four, Suit::Hearts),
+                    Card(Rank::Two, Suit::Spades)
+                ]],
+                vec![vec![
+                    Card(Rank::Jack, Suit::Spades),
+                    Card(Rank::Three, Suit::Hearts)
+                ]],
+            ]
+        );
+        assert_eq!(table.settle().unwrap(), vec![-2, 2]);
+    }
+
+    #[test]
+    fn test_kicker_decides_a_runout() {
+        let mut table = Table::new(Config::new(1, 2), vec![100, 100], 0, Deck::new(65)).unwrap();
+
+        assert_ok!(table.act(Action::AllIn));
+        assert_ok!(table.act(Action::AllIn));
+
+        let boards = table.run_it(2).unwrap();
+
+        // Eights and threes with a queen kicker tie the first run; on the
+        // second the nine outkicks the seven behind the pair of threes.
+        assert_eq!(
+            boards[1],
+            vec![
+                Card(Rank::Ace, Suit::Clubs),
+                Card(Rank::Three, Suit::Diamonds),
+                Card(Rank::Six, Suit::Spades),
+                Card(Rank::King, Suit::Diamonds),
+                Card(Rank::Seven, Suit::Hearts),
+            ]
+        );
+        assert_eq!(table.settle().unwrap(), vec![50, -50]);
+    }
+}
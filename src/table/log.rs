@@ -0,0 +1,389 @@
+use std::io::Error;
+use std::io::ErrorKind;
+
+use crate::card::Deck;
+use crate::table::Event;
+use crate::table::Rake;
+use crate::table::Table;
+
+impl Table {
+    pub fn log(&self) -> Vec<Event> {
+        self.log.clone()
+    }
+
+    pub fn award(&mut self, rake: Option<&Rake>) -> Result<Vec<i64>, Error> {
+        if self.awarded {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "pots have already been awarded",
+            ));
+        }
+
+        let ledger = match rake {
+            Some(rake) => {
+                let (ledger, taken) = self.settle_with_rake(rake)?;
+                let taken = taken.iter().sum::<u64>();
+
+                if taken > 0 {
+                    self.log.push(Event::Rake {
+                        rake: rake.clone(),
+                        amount: taken,
+                    });
+                }
+
+                ledger
+            }
+            None => self.settle()?,
+        };
+
+        for (seat, net) in ledger.iter().enumerate() {
+            let amount = (self.seats[seat].committed as i64 + net) as u64;
+
+            if amount > 0 {
+                self.seats[seat].stack += amount;
+                self.log.push(Event::Award { seat, amount });
+            }
+        }
+
+        self.awarded = true;
+
+        Ok(ledger)
+    }
+
+    // Rebuilds a hand from the seed, config, stacks and button recorded in
+    // its opening event by re-applying the player decisions in `events`,
+    // show and muck included, and checks that every other event is
+    // reproduced exactly. Pots are re-awarded with the logged rake, so a
+    // rake or award that does not match the settlement is rejected.
+    pub fn replay(events: &[Event]) -> Result<Self, Error> {
+        let (config, stacks, button, seed) = match events.first() {
+            Some(Event::Start {
+                config,
+                stacks,
+                button,
+                seed,
+            }) => (*config, stacks.clone(), *button, *seed),
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "events do not start with a hand",
+                ))
+            }
+        };
+
+        let seed = seed.ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidData,
+                "hand was not dealt from a seeded deck",
+            )
+        })?;
+
+        let mut table = Self::new(config, stacks, button, Deck::new(seed))?;
+
+        for event in events {
+            match event {
+                Event::Return { seat, returning } => table.post_returning(*seat, *returning)?,
+                Event::Act { seat, action } => {
+                    if table.to_act != Some(*seat) {
+                        return Err(Error::new(ErrorKind::InvalidData, "action out of turn"));
+                    }
+
+                    table.act(*action)?;
+                }
+                Event::RunIt { boards } => {
+                    table.run_it(boards.len() + 1)?;
+                }
+                Event::Rake { rake, amount: _ } => {
+                    if !table.awarded {
+                        table.award(Some(rake))?;
+                    }
+                }
+                Event::Award { seat: _, amount: _ } => {
+                    if !table.awarded {
+                        table.award(None)?;
+                    }
+                }
+                Event::Start {
+                    config: _,
+                    stacks: _,
+                    button: _,
+                    seed: _,
+                }
+                | Event::Deal { seat: _, cards: _ }
+                | Event::Post {
+                    seat: _,
+                    amount: _,
+                    dead: _,
+                }
+                | Event::Street {
+                    street: _,
+                    cards: _,
+                }
+                | Event::Uncalled { seat: _, amount: _ }
+                | Event::Showdown { seat: _, cards: _ }
+                | Event::Muck { seat: _ } => (),
+            }
+        }
+
+        if table.log != events {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                "events do not match the replayed hand",
+            ));
+        }
+
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use crate::card::Deck;
+    use crate::table::Action;
+    use crate::table::Ante;
+    use crate::table::Config;
+    use crate::table::Event;
+    use crate::table::Rake;
+    use crate::table::Returning;
+    use crate::table::Street;
+    use crate::table::Table;
+
+    fn played(rake: Option<&Rake>) -> Table {
+        let mut table =
+            Table::new(Config::new(1, 2), vec![50, 100, 100, 100], 0, Deck::new(7)).unwrap();
+
+        table.post_returning(3, Returning::Dead).unwrap();
+        table.act(Action::AllIn).unwrap();
+        table.act(Action::Call).unwrap();
+        table.act(Action::Fold).unwrap();
+        table.act(Action::Fold).unwrap();
+        table.run_it(2).unwrap();
+        table.award(rake).unwrap();
+
+        table
+    }
+
+    #[test]
+    fn test_log() {
+        let log = played(None).log();
+
+        assert_eq!(
+            log[0],
+            Event::Start {
+                config: Config::new(1, 2),
+                stacks: vec![50, 100, 100, 100],
+                button: 0,
+                seed: Some(7)
+            }
+        );
+        assert_eq!(
+            log.iter()
+                .filter(|event| matches!(event, Event::Deal { seat: _, cards: _ }))
+                .count(),
+            4
+        );
+        assert_eq!(
+            log[5..8].to_vec(),
+            vec![
+                Event::Post {
+                    seat: 1,
+                    amount: 1,
+                    dead: false
+                },
+                Event::Post {
+                    seat: 2,
+                    amount: 2,
+                    dead: false
+                },
+                Event::Return {
+                    seat: 3,
+                    returning: Returning::Dead
+                },
+            ]
+        );
+        assert!(log.contains(&Event::Street {
+            street: Street::Showdown,
+            cards: vec![]
+        }));
+        assert!(log.contains(&Event::Uncalled {
+            seat: 3,
+            amount: 49
+        }));
+
+        let awarded = log
+            .iter()
+            .map(|event| match event {
+                Event::Award { seat: _, amount } => *amount,
+                _ => 0,
+            })
+            .sum::<u64>();
+
+        assert_eq!(awarded, 50 + 1 + 2 + 51);
+    }
+
+    #[test]
+    fn test_award_once() {
+        let mut table = played(None);
+
+        assert_err!(table.award(None));
+        assert_eq!(
+            table.seats().iter().map(|seat| seat.stack()).sum::<u64>(),
+            350
+        );
+    }
+
+    #[test]
+    fn test_replay() {
+        let table = played(None);
+        let replayed = Table::replay(&table.log()).unwrap();
+
+        assert_eq!(replayed.log(), table.log());
+        assert_eq!(replayed.seats(), table.seats());
+        assert_eq!(replayed.boards(), table.boards());
+
+        let tamper = |n: usize, event: Event| {
+            let mut tampered = table.log();
+
+            tampered[n] = event;
+            tampered
+        };
+
+        assert_err!(Table::replay(&table.log()[1..]));
+        assert_err!(Table::replay(&tamper(
+            0,
+            Event::Start {
+                config: Config::new(1, 2),
+                stacks: vec![50, 100, 100, 100],
+                button: 0,
+                seed: Some(8)
+            }
+        )));
+        assert_err!(Table::replay(&tamper(
+            0,
+            Event::Start {
+                config: Config::new(1, 2),
+                stacks: vec![60, 100, 100, 100],
+                button: 0,
+                seed: Some(7)
+            }
+        )));
+        assert_err!(Table::replay(&tamper(
+            1,
+            Event::Deal {
+                seat: 0,
+                cards: vec![]
+            }
+        )));
+    }
+
+    #[test]
+    fn test_replay_unseeded() {
+        let table = Table::new(
+            Config::new(1, 2),
+            vec![100, 100],
+            0,
+            Deck::stacked(Deck::new(0).cards()),
+        )
+        .unwrap();
+
+        assert_err!(Table::replay(&table.log()));
+    }
+
+    #[test]
+    fn test_replay_award() {
+        let table = played(Some(&Rake::new(500)));
+        let log = table.log();
+
+        assert!(log.contains(&Event::Rake {
+            rake: Rake::new(500),
+            amount: 5
+        }));
+        assert_eq!(Table::replay(&log).unwrap().seats(), table.seats());
+
+        let tampered = |change: &dyn Fn(&mut Event)| {
+            let mut tampered = log.clone();
+
+            tampered.iter_mut().for_each(change);
+            tampered
+        };
+
+        assert_err!(Table::replay(&tampered(&|event| {
+            if let Event::Award { seat: 0, amount } = event {
+                *amount += 1;
+            }
+        })));
+        assert_err!(Table::replay(&tampered(&|event| {
+            if let Event::Rake { rake: _, amount } = event {
+                *amount += 1;
+            }
+        })));
+        assert_err!(Table::replay(&tampered(&|event| {
+            if let Event::Rake { rake, amount: _ } = event {
+                rake.percent = 1000;
+            }
+        })));
+    }
+
+    #[test]
+    fn test_replay_with_antes() {
+        let config = Config {
+            ante: Some(Ante::Button(2)),
+            ..Config::new(1, 2)
+        };
+        let mut table = Table::new(config, vec![100, 100, 30], 0, Deck::new(3)).unwrap();
+
+        table.act(Action::AllIn).unwrap();
+        table.act(Action::Fold).unwrap();
+        table.act(Action::Call).unwrap();
+        table.award(None).unwrap();
+
+        assert!(table.log().contains(&Event::Uncalled {
+            seat: 0,
+            amount: 68
+        }));
+
+        let replayed = Table::replay(&table.log()).unwrap();
+
+        assert_eq!(replayed.seats(), table.seats());
+    }
+
+    #[test]
+    fn test_replay_show_and_muck() {
+        let mut table =
+            Table::new(Config::new(1, 2), vec![100, 100, 100], 0, Deck::new(5)).unwrap();
+
+        table.act(Action::Call).unwrap();
+        table.act(Action::Call).unwrap();
+
+        for _ in 0..10 {
+            table.act(Action::Check).unwrap();
+        }
+
+        table.act(Action::Show).unwrap();
+        table.act(Action::Muck).unwrap();
+        table.award(None).unwrap();
+
+        let log = table.log();
+
+        assert_eq!(table.revealed().len(), 2);
+        assert_eq!(Table::replay(&log).unwrap().log(), log);
+
+        let tampered = log
+            .iter()
+            .map(|event| match event {
+                Event::Act {
+                    seat,
+                    action: Action::Muck,
+                } => Event::Act {
+                    seat: *seat,
+                    action: Action::Show,
+                },
+                event => event.clone(),
+            })
+            .collect::<Vec<_>>();
+
+        assert_err!(Table::replay(&tampered));
+    }
+}
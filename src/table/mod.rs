@@ -0,0 +1,237 @@
+mod impl_table;
+mod log;
+mod pots;
+mod rake;
+mod showdown;
+mod structure;
+mod view;
+
+use crate::card::Card;
+use crate::card::Deck;
+use crate::game::HandOf2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Street {
+    Preflop,
+    Flop,
+    Turn,
+    River,
+    Showdown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Action {
+    Fold,
+    Check,
+    Call,
+    Bet(u64),
+    Raise(u64),
+    AllIn,
+    Show,
+    Muck,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Event {
+    Start {
+        config: Config,
+        stacks: Vec<u64>,
+        button: usize,
+        seed: Option<u64>,
+    },
+    Deal {
+        seat: usize,
+        cards: Vec<Card>,
+    },
+    Post {
+        seat: usize,
+        amount: u64,
+        dead: bool,
+    },
+    Return {
+        seat: usize,
+        returning: Returning,
+    },
+    Act {
+        seat: usize,
+        action: Action,
+    },
+    Street {
+        street: Street,
+        cards: Vec<Card>,
+    },
+    RunIt {
+        boards: Vec<Vec<Card>>,
+    },
+    Uncalled {
+        seat: usize,
+        amount: u64,
+    },
+    Showdown {
+        seat: usize,
+        cards: Vec<Card>,
+    },
+    Muck {
+        seat: usize,
+    },
+    Rake {
+        rake: Rake,
+        amount: u64,
+    },
+    Award {
+        seat: usize,
+        amount: u64,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Legal {
+    Fold,
+    Check,
+    Call(u64),
+    Bet { min: u64, max: u64 },
+    Raise { min: u64, max: u64 },
+    AllIn(u64),
+    Show,
+    Muck,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Structure {
+    NoLimit,
+    PotLimit,
+    FixedLimit { cap: usize },
+    SpreadLimit { min: u64, max: u64 },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Ante {
+    Classic(u64),
+    BigBlind(u64),
+    Button(u64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Straddle {
+    Utg,
+    Mississippi,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Returning {
+    Posted,
+    Dead,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OddChip {
+    LeftOfButton,
+    BySuit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    pub small_blind: u64,
+    pub big_blind: u64,
+    pub ante: Option<Ante>,
+    pub straddle: Option<Straddle>,
+    pub odd_chip: OddChip,
+    pub structure: Structure,
+}
+
+impl Config {
+    pub fn new(small_blind: u64, big_blind: u64) -> Self {
+        Self {
+            small_blind,
+            big_blind,
+            ante: None,
+            straddle: None,
+            odd_chip: OddChip::LeftOfButton,
+            structure: Structure::NoLimit,
+        }
+    }
+}
+
+// Percentages are in basis points. Caps are keyed by the minimum number of
+// players dealt in and by the last street reached.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rake {
+    pub percent: u64,
+    pub caps: Vec<(usize, u64)>,
+    pub street_caps: Vec<(Street, u64)>,
+    pub no_flop_no_drop: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fee {
+    Fixed(u64),
+    Percentage(u64),
+    Withheld(u64),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pot {
+    pub(crate) amount: u64,
+    pub(crate) eligible: Vec<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Seat {
+    pub(crate) stack: u64,
+    pub(crate) hand: HandOf2,
+    pub(crate) bet: u64,
+    pub(crate) committed: u64,
+    pub(crate) dead: u64,
+    pub(crate) folded: bool,
+    pub(crate) acted: Option<u64>,
+    pub(crate) posted: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Viewer {
+    Player(usize),
+    Spectator,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeatView {
+    pub(crate) stack: u64,
+    pub(crate) bet: u64,
+    pub(crate) committed: u64,
+    pub(crate) folded: bool,
+    pub(crate) cards: Option<Vec<Card>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct View {
+    pub(crate) viewer: Viewer,
+    pub(crate) seats: Vec<SeatView>,
+    pub(crate) button: usize,
+    pub(crate) boards: Vec<Vec<Card>>,
+    pub(crate) street: Street,
+    pub(crate) to_act: Option<usize>,
+    pub(crate) current_bet: u64,
+    pub(crate) pot: u64,
+    pub(crate) events: Vec<Event>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    pub(crate) config: Config,
+    pub(crate) seats: Vec<Seat>,
+    pub(crate) button: usize,
+    pub(crate) deck: Deck,
+    pub(crate) burned: Vec<Card>,
+    pub(crate) board: Vec<Card>,
+    pub(crate) runouts: Vec<Vec<Card>>,
+    pub(crate) locked: Option<usize>,
+    pub(crate) street: Street,
+    pub(crate) to_act: Option<usize>,
+    pub(crate) current_bet: u64,
+    pub(crate) full_bet: u64,
+    pub(crate) last_raise: u64,
+    pub(crate) bets: usize,
+    pub(crate) aggressor: Option<usize>,
+    pub(crate) log: Vec<Event>,
+    pub(crate) awarded: bool,
+}
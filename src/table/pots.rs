@@ -0,0 +1,289 @@
+use ::itertools::Itertools;
+
+use crate::card::Card;
+use crate::table::OddChip;
+use crate::table::Pot;
+
+impl Pot {
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    pub fn eligible(&self) -> Vec<usize> {
+        self.eligible.clone()
+    }
+
+    // `dead` is the part of each contribution that was posted dead (antes
+    // and dead blinds). It does not entitle the poster to a side pot and
+    // always goes to the main pot.
+    pub fn build(contributions: &[u64], dead: &[u64], folded: &[bool]) -> Vec<Self> {
+        let live = contributions
+            .iter()
+            .zip(dead)
+            .map(|(contribution, dead)| contribution - dead)
+            .collect_vec();
+        let dead = dead.iter().sum::<u64>();
+
+        let levels = live
+            .iter()
+            .zip(folded)
+            .filter(|(_, folded)| !**folded)
+            .map(|(contribution, _)| *contribution)
+            .filter(|contribution| *contribution > 0)
+            .sorted()
+            .dedup()
+            .collect_vec();
+
+        if levels.is_empty() {
+            let eligible = (0..contributions.len())
+                .filter(|seat| !folded[*seat])
+                .collect_vec();
+
+            return match live.iter().sum::<u64>() + dead {
+                0 => vec![],
+                amount => vec![Self { amount, eligible }],
+            };
+        }
+
+        let mut pots: Vec<Self> = vec![];
+        let mut previous = 0;
+
+        for (n, level) in levels.iter().enumerate() {
+            // Dead money above the last live level goes to the last pot.
+            let level = if n == levels.len() - 1 {
+                u64::MAX
+            } else {
+                *level
+            };
+
+            let amount = live
+                .iter()
+                .map(|contribution| contribution.min(&level) - contribution.min(&previous))
+                .sum::<u64>()
+                + if n == 0 { dead } else { 0 };
+
+            let eligible = (0..contributions.len())
+                .filter(|seat| !folded[*seat] && (n == 0 || live[*seat] > previous))
+                .collect_vec();
+
+            pots.push(Self { amount, eligible });
+            previous = level;
+        }
+
+        pots
+    }
+
+    // `places` lists seats grouped by hand strength, weakest first, as in
+    // `Game::sort_hands`. `order` is the seat order used for odd chips,
+    // starting from the first seat left of the button.
+    pub fn award(
+        pots: &[Self],
+        places: &[Vec<usize>],
+        odd_chip: OddChip,
+        order: &[usize],
+        hands: &[Vec<Card>],
+    ) -> Vec<u64> {
+        let mut winnings = vec![0; hands.len()];
+
+        for pot in pots {
+            let winners = match places
+                .iter()
+                .rev()
+                .map(|group| {
+                    group
+                        .iter()
+                        .copied()
+                        .filter(|seat| pot.eligible.contains(seat))
+                        .collect_vec()
+                })
+                .find(|group| !group.is_empty())
+            {
+                Some(winners) => Self::odd_chip_order(winners, odd_chip, order, hands),
+                None => continue,
+            };
+
+            let share = pot.amount / winners.len() as u64;
+            let odd_chips = pot.amount % winners.len() as u64;
+
+            winners.iter().enumerate().for_each(|(n, winner)| {
+                winnings[*winner] += share + if (n as u64) < odd_chips { 1 } else { 0 };
+            });
+        }
+
+        winnings
+    }
+
+    pub fn ledger(contributions: &[u64], winnings: &[u64]) -> Vec<i64> {
+        contributions
+            .iter()
+            .zip(winnings)
+            .map(|(contribution, won)| *won as i64 - *contribution as i64)
+            .collect()
+    }
+
+    pub fn places(sorted: Vec<Vec<Vec<Card>>>, hands: &[Vec<Card>]) -> Vec<Vec<usize>> {
+        sorted
+            .into_iter()
+            .map(|group| {
+                group
+                    .into_iter()
+                    .filter_map(|hand| hands.iter().position(|cards| *cards == hand))
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn odd_chip_order(
+        winners: Vec<usize>,
+        odd_chip: OddChip,
+        order: &[usize],
+        hands: &[Vec<Card>],
+    ) -> Vec<usize> {
+        match odd_chip {
+            OddChip::LeftOfButton => winners
+                .into_iter()
+                .sorted_by_key(|seat| order.iter().position(|s| s == seat))
+                .collect(),
+            OddChip::BySuit => winners
+                .into_iter()
+                .sorted_by_key(|seat| {
+                    std::cmp::Reverse(
+                        hands[*seat]
+                            .iter()
+                            .map(|card| (card.rank(), card.suit().precedence()))
+                            .max(),
+                    )
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::Card;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::table::OddChip;
+    use crate::table::Pot;
+
+    fn hands() -> Vec<Vec<Card>> {
+        vec![
+            vec![Card(Rank::Ace, Suit::Clubs), Card(Rank::Two, Suit::Clubs)],
+            vec![Card(Rank::Ace, Suit::Spades), Card(Rank::Two, Suit::Hearts)],
+            vec![
+                Card(Rank::King, Suit::Clubs),
+                Card(Rank::King, Suit::Hearts),
+            ],
+            vec![
+                Card(Rank::Queen, Suit::Clubs),
+                Card(Rank::Jack, Suit::Hearts),
+            ],
+        ]
+    }
+
+    #[test]
+    fn test_build() {
+        let pots = Pot::build(&[50, 100, 200, 200], &[0; 4], &[false, false, false, true]);
+
+        assert_eq!(pots.len(), 3);
+        assert_eq!(pots[0].amount(), 200);
+        assert_eq!(pots[0].eligible(), vec![0, 1, 2]);
+        assert_eq!(pots[1].amount(), 150);
+        assert_eq!(pots[1].eligible(), vec![1, 2]);
+        assert_eq!(pots[2].amount(), 200);
+        assert_eq!(pots[2].eligible(), vec![2]);
+    }
+
+    #[test]
+    fn test_build_dead_money() {
+        let pots = Pot::build(&[100, 100, 150], &[0; 3], &[false, false, true]);
+
+        assert_eq!(pots.len(), 1);
+        assert_eq!(pots[0].amount(), 350);
+        assert_eq!(pots[0].eligible(), vec![0, 1]);
+    }
+
+    #[test]
+    fn test_build_antes_go_to_the_main_pot() {
+        let pots = Pot::build(&[4, 102, 102], &[2, 2, 2], &[false, false, false]);
+
+        assert_eq!(pots.len(), 2);
+        assert_eq!(pots[0].amount(), 12);
+        assert_eq!(pots[0].eligible(), vec![0, 1, 2]);
+        assert_eq!(pots[1].amount(), 196);
+        assert_eq!(pots[1].eligible(), vec![1, 2]);
+
+        let pots = Pot::build(&[2, 4, 3], &[0, 2, 1], &[false, false, false]);
+
+        assert_eq!(
+            pots,
+            vec![Pot {
+                amount: 9,
+                eligible: vec![0, 1, 2]
+            }]
+        );
+    }
+
+    #[test]
+    fn test_award_side_pots() {
+        let contributions = [50, 100, 200, 200];
+        let pots = Pot::build(&contributions, &[0; 4], &[false, false, false, true]);
+        let places = vec![vec![2], vec![1], vec![0]];
+        let winnings = Pot::award(
+            &pots,
+            &places,
+            OddChip::LeftOfButton,
+            &[0, 1, 2, 3],
+            &hands(),
+        );
+
+        assert_eq!(winnings, vec![200, 150, 200, 0]);
+
+        let ledger = Pot::ledger(&contributions, &winnings);
+
+        assert_eq!(ledger, vec![150, 50, 0, -200]);
+        assert_eq!(ledger.iter().sum::<i64>(), 0);
+    }
+
+    #[test]
+    fn test_award_odd_chip_left_of_button() {
+        let contributions = [35, 35, 35];
+        let pots = Pot::build(&contributions, &[0; 3], &[false, false, false]);
+        let places = vec![vec![2], vec![0, 1]];
+        let winnings = Pot::award(
+            &pots,
+            &places,
+            OddChip::LeftOfButton,
+            &[1, 2, 0],
+            &hands()[..3],
+        );
+
+        assert_eq!(winnings, vec![52, 53, 0]);
+        assert_eq!(
+            Pot::ledger(&contributions, &winnings).iter().sum::<i64>(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_award_odd_chip_by_suit() {
+        let contributions = [35, 35, 35];
+        let pots = Pot::build(&contributions, &[0; 3], &[false, false, false]);
+        let places = vec![vec![2], vec![0, 1]];
+        let winnings = Pot::award(&pots, &places, OddChip::BySuit, &[0, 1, 2], &hands()[..3]);
+
+        assert_eq!(winnings, vec![52, 53, 0]);
+    }
+
+    #[test]
+    fn test_places() {
+        let hands = hands();
+        let sorted = vec![
+            vec![hands[3].clone()],
+            vec![hands[0].clone(), hands[1].clone()],
+        ];
+
+        assert_eq!(Pot::places(sorted, &hands), vec![vec![3], vec![0, 1]]);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::table::Fee;
+use crate::table::Pot;
+use crate::table::Rake;
+use crate::table::Street;
+
+impl Rake {
+    pub fn new(percent: u64) -> Self {
+        Self {
+            percent,
+            caps: vec![],
+            street_caps: vec![],
+            no_flop_no_drop: false,
+        }
+    }
+
+    pub fn cap(&self, players: usize, street: Street) -> Option<u64> {
+        let by_players = self
+            .caps
+            .iter()
+            .filter(|(min, _)| *min <= players)
+            .max_by_key(|(min, _)| *min)
+            .map(|(_, cap)| *cap);
+
+        let by_street = self
+            .street_caps
+            .iter()
+            .find(|(s, _)| *s == street)
+            .map(|(_, cap)| *cap);
+
+        match (by_players, by_street) {
+            (Some(a), Some(b)) => Some(a.min(b)),
+            (a, b) => a.or(b),
+        }
+    }
+
+    // Returns the rake taken from each pot, main pot first. Uncalled bets
+    // are returned before pots are built, so every pot holds called or dead
+    // money and is raked even when it is won without a showdown.
+    pub fn take(&self, pots: &[Pot], players: usize, street: Street) -> Vec<u64> {
+        if self.no_flop_no_drop && street == Street::Preflop {
+            return vec![0; pots.len()];
+        }
+
+        let mut remaining = self.cap(players, street).unwrap_or(u64::MAX);
+
+        pots.iter()
+            .map(|pot| {
+                let rake = (pot.amount * self.percent / 10_000).min(remaining);
+                remaining -= rake;
+
+                rake
+            })
+            .collect()
+    }
+}
+
+impl Fee {
+    // Splits an entry into its prize pool contribution and the fee, given
+    // the advertised buy-in.
+    pub fn split(&self, buy_in: u64) -> (u64, u64) {
+        match self {
+            Self::Fixed(fee) => (buy_in, *fee),
+            Self::Percentage(percent) => (buy_in, buy_in * percent / 10_000),
+            Self::Withheld(percent) => {
+                let fee = buy_in * percent / 10_000;
+
+                (buy_in - fee, fee)
+            }
+        }
+    }
+
+    pub fn collect(&self, buy_in: u64, entries: usize) -> (u64, u64) {
+        let (prize, fee) = self.split(buy_in);
+
+        (prize * entries as u64, fee * entries as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::table::Fee;
+    use crate::table::Pot;
+    use crate::table::Rake;
+    use crate::table::Street;
+
+    fn rake() -> Rake {
+        Rake {
+            caps: vec![(2, 5), (5, 10)],
+            street_caps: vec![(Street::Flop, 3)],
+            ..Rake::new(500)
+        }
+    }
+
+    #[test]
+    fn test_cap() {
+        assert_eq!(rake().cap(2, Street::River), Some(5));
+        assert_eq!(rake().cap(6, Street::River), Some(10));
+        assert_eq!(rake().cap(6, Street::Flop), Some(3));
+        assert_eq!(Rake::new(500).cap(6, Street::River), None);
+    }
+
+    #[test]
+    fn test_take() {
+        let pots = vec![
+            Pot {
+                amount: 100,
+                eligible: vec![0, 1, 2],
+            },
+            Pot {
+                amount: 100,
+                eligible: vec![1, 2],
+            },
+            Pot {
+                amount: 40,
+                eligible: vec![2],
+            },
+        ];
+
+        assert_eq!(rake().take(&pots, 6, Street::River), vec![5, 5, 0]);
+        assert_eq!(rake().take(&pots, 3, Street::River), vec![5, 0, 0]);
+        assert_eq!(rake().take(&pots, 6, Street::Flop), vec![3, 0, 0]);
+        assert_eq!(Rake::new(500).take(&pots, 6, Street::River), vec![5, 5, 2]);
+        assert_eq!(
+            Rake::new(500).take(&pots, 6, Street::Preflop),
+            vec![5, 5, 2]
+        );
+
+        let rake = Rake {
+            no_flop_no_drop: true,
+            ..Rake::new(500)
+        };
+
+        assert_eq!(rake.take(&pots, 6, Street::Preflop), vec![0, 0, 0]);
+        assert_eq!(rake.take(&pots, 6, Street::Flop), vec![5, 5, 2]);
+    }
+
+    #[test]
+    fn test_fee() {
+        assert_eq!(Fee::Fixed(9).split(100), (100, 9));
+        assert_eq!(Fee::Percentage(1000).split(100), (100, 10));
+        assert_eq!(Fee::Withheld(1000).split(100), (90, 10));
+        assert_eq!(Fee::Withheld(1000).collect(100, 20), (1800, 200));
+    }
+}
@@ -0,0 +1,299 @@
+use std::io::Error;
+use std::io::ErrorKind;
+
+use crate::combination::Combination;
+use crate::game::Hand;
+use crate::table::Action;
+use crate::table::Event;
+use crate::table::Seat;
+use crate::table::Table;
+
+impl Table {
+    pub fn revealed(&self) -> Vec<usize> {
+        self.log
+            .iter()
+            .filter_map(|event| match event {
+                Event::Showdown { seat, cards: _ } => Some(*seat),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn mucked(&self) -> Vec<usize> {
+        self.log
+            .iter()
+            .filter_map(|event| match event {
+                Event::Muck { seat } => Some(*seat),
+                _ => None,
+            })
+            .collect()
+    }
+
+    // Only live bets can be called; dead blinds and antes stay in the pot.
+    pub(crate) fn return_uncalled(&mut self) {
+        let live = |seat: &Seat| seat.committed - seat.dead;
+
+        let top = match (0..self.seats.len()).max_by_key(|seat| live(&self.seats[*seat])) {
+            Some(top) if !self.seats[top].folded => top,
+            _ => return,
+        };
+
+        let called = (0..self.seats.len())
+            .filter(|seat| *seat != top)
+            .map(|seat| live(&self.seats[seat]))
+            .max()
+            .unwrap_or(0);
+
+        let amount = live(&self.seats[top]).saturating_sub(called);
+
+        if amount > 0 {
+            let seat = &mut self.seats[top];
+
+            seat.stack += amount;
+            seat.committed -= amount;
+            seat.bet = seat.bet.saturating_sub(amount);
+
+            self.log.push(Event::Uncalled { seat: top, amount });
+        }
+    }
+
+    pub(crate) fn show_or_muck(&mut self, seat: usize, action: Action) -> Result<(), Error> {
+        match action {
+            Action::Show => {
+                let cards = self.seats[seat].hand.cards();
+
+                self.log.push(Event::Act { seat, action });
+                self.log.push(Event::Showdown { seat, cards });
+            }
+            Action::Muck => {
+                self.seats[seat].folded = true;
+                self.log.push(Event::Act { seat, action });
+                self.log.push(Event::Muck { seat });
+            }
+            _ => {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    "only show or muck at showdown",
+                ))
+            }
+        }
+
+        self.reveal();
+
+        Ok(())
+    }
+
+    // The last aggressor on the river shows first, otherwise the first
+    // player left of the button. Each later player in turn has to show a
+    // hand that beats or ties the best hand shown so far in any pot they
+    // play for, or that plays for a pot nobody has shown in yet; a beaten
+    // hand may be shown or mucked. All-in hands are always shown. Reveals
+    // stop at each player with a choice, and a mucked hand gives up its
+    // claim to the pots.
+    pub(crate) fn reveal(&mut self) {
+        let players = self.seats.len();
+
+        let start = self
+            .log
+            .iter()
+            .find_map(|event| match event {
+                Event::Showdown { seat, cards: _ } | Event::Muck { seat } => Some(*seat),
+                _ => None,
+            })
+            .unwrap_or_else(|| match self.aggressor {
+                Some(seat) if self.locked.is_none() && !self.seats[seat].folded => seat,
+                _ => (1..=players)
+                    .map(|n| (self.button + n) % players)
+                    .find(|seat| !self.seats[*seat].folded)
+                    .unwrap(),
+            });
+
+        let pots = self
+            .pots()
+            .into_iter()
+            .filter(|pot| pot.eligible.len() > 1)
+            .collect::<Vec<_>>();
+
+        let strengths = self
+            .seats
+            .iter()
+            .map(|seat| Combination::strength(&[&seat.hand.cards()[..], &self.board[..]].concat()))
+            .collect::<Vec<_>>();
+
+        let mut shown = self.revealed();
+
+        for seat in (0..players).map(|n| (start + n) % players) {
+            if self.seats[seat].folded || shown.contains(&seat) {
+                continue;
+            }
+
+            let beaten = pots
+                .iter()
+                .filter(|pot| pot.eligible.contains(&seat))
+                .all(|pot| {
+                    shown
+                        .iter()
+                        .filter(|other| pot.eligible.contains(other))
+                        .map(|other| strengths[*other])
+                        .max()
+                        .is_some_and(|best| best > strengths[seat])
+                });
+
+            if beaten && self.locked.is_none() {
+                self.to_act = Some(seat);
+                return;
+            }
+
+            let cards = self.seats[seat].hand.cards();
+
+            self.log.push(Event::Showdown { seat, cards });
+            shown.push(seat);
+        }
+
+        self.to_act = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use crate::card::Card;
+    use crate::card::Deck;
+    use crate::card::Rank;
+    use crate::card::Suit;
+    use crate::table::Action;
+    use crate::table::Ante;
+    use crate::table::Config;
+    use crate::table::Event;
+    use crate::table::Legal;
+    use crate::table::Table;
+
+    fn table() -> Table {
+        let deck = Deck::stacked(vec![
+            Card(Rank::Seven, Suit::Hearts),
+            Card(Rank::King, Suit::Clubs),
+            Card(Rank::Ace, Suit::Clubs),
+            Card(Rank::Two, Suit::Spades),
+            Card(Rank::King, Suit::Diamonds),
+            Card(Rank::Ace, Suit::Diamonds),
+            Card(Rank::Four, Suit::Hearts),
+            Card(Rank::Two, Suit::Clubs),
+            Card(Rank::Five, Suit::Diamonds),
+            Card(Rank::Nine, Suit::Hearts),
+            Card(Rank::Four, Suit::Spades),
+            Card(Rank::Jack, Suit::Spades),
+            Card(Rank::Six, Suit::Spades),
+            Card(Rank::Three, Suit::Clubs),
+        ]);
+
+        let mut table = Table::new(Config::new(1, 2), vec![100, 100, 100], 0, deck).unwrap();
+
+        table.act(Action::Call).unwrap();
+        table.act(Action::Call).unwrap();
+        table.act(Action::Check).unwrap();
+
+        for _ in 0..6 {
+            table.act(Action::Check).unwrap();
+        }
+
+        table
+    }
+
+    #[test]
+    fn test_last_aggressor_shows_first() {
+        let mut table = table();
+
+        table.act(Action::Check).unwrap();
+        table.act(Action::Bet(10)).unwrap();
+        table.act(Action::Call).unwrap();
+        table.act(Action::Call).unwrap();
+
+        assert_eq!(table.revealed(), vec![2, 0]);
+        assert_eq!(table.to_act(), Some(1));
+        assert_eq!(table.legal_actions(), vec![Legal::Show, Legal::Muck]);
+        assert_err!(table.act(Action::Check));
+        assert_err!(table.settle());
+
+        table.act(Action::Muck).unwrap();
+
+        assert!(table.is_complete());
+        assert_eq!(table.revealed(), vec![2, 0]);
+        assert_eq!(table.mucked(), vec![1]);
+        assert_eq!(table.settle().unwrap(), vec![24, -12, -12]);
+    }
+
+    #[test]
+    fn test_winning_hand_cannot_muck() {
+        let mut table = table();
+
+        table.act(Action::Bet(10)).unwrap();
+        table.act(Action::Call).unwrap();
+        table.act(Action::Call).unwrap();
+
+        // The aggressor shows deuces first; the kings and then the aces
+        // beat the best hand shown so far, so neither gets a choice.
+        assert_eq!(table.revealed(), vec![1, 2, 0]);
+        assert!(table.mucked().is_empty());
+        assert!(table.is_complete());
+        assert_err!(table.act(Action::Muck));
+        assert_eq!(table.settle().unwrap(), vec![24, -12, -12]);
+    }
+
+    #[test]
+    fn test_checked_down() {
+        let mut table = table();
+
+        for _ in 0..3 {
+            table.act(Action::Check).unwrap();
+        }
+
+        assert_eq!(table.revealed(), vec![1, 2, 0]);
+        assert!(table.is_complete());
+        assert_err!(table.act(Action::Fold));
+        assert!(table.mucked().is_empty());
+    }
+
+    #[test]
+    fn test_show_before_showdown() {
+        let mut table = table();
+
+        assert_err!(table.act(Action::Show));
+        assert_err!(table.act(Action::Muck));
+    }
+
+    #[test]
+    fn test_uncalled_bet_over_dead_money() {
+        let config = Config {
+            ante: Some(Ante::BigBlind(2)),
+            ..Config::new(1, 2)
+        };
+        let mut table = Table::new(config, vec![300, 300, 50, 200], 0, Deck::new(0)).unwrap();
+
+        table.act(Action::AllIn).unwrap();
+        table.act(Action::Fold).unwrap();
+        table.act(Action::Fold).unwrap();
+        table.act(Action::Call).unwrap();
+
+        assert!(table.log().contains(&Event::Uncalled {
+            seat: 3,
+            amount: 152
+        }));
+        assert_eq!(table.pot(), 1 + 50 + 48);
+    }
+
+    #[test]
+    fn test_uncalled_bet_returned() {
+        let mut table = table();
+
+        table.act(Action::Check).unwrap();
+        table.act(Action::Bet(10)).unwrap();
+        table.act(Action::Fold).unwrap();
+        table.act(Action::Fold).unwrap();
+
+        assert_eq!(table.pot(), 6);
+        assert_eq!(table.seats()[2].stack(), 98);
+        assert!(table.revealed().is_empty());
+        assert_eq!(table.settle().unwrap(), vec![-2, -2, 4]);
+    }
+}
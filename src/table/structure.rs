@@ -0,0 +1,62 @@
+use crate::table::Street;
+use crate::table::Structure;
+use crate::table::Table;
+
+impl Structure {
+    pub fn bet_size(&self, street: Street, big_blind: u64) -> u64 {
+        match (self, street) {
+            (Self::FixedLimit { cap: _ }, Street::Turn)
+            | (Self::FixedLimit { cap: _ }, Street::River) => big_blind * 2,
+            (Self::SpreadLimit { min, max: _ }, _) => *min,
+            _ => big_blind,
+        }
+    }
+
+    // Returns the smallest and the largest legal total bet for `seat`,
+    // capped by its stack, or `None` if the seat may not bet or raise.
+    pub(crate) fn raise_bounds(&self, table: &Table, seat: usize) -> Option<(u64, u64)> {
+        let bet = table.seats[seat].bet;
+        let stack = table.seats[seat].stack;
+        let all_in = bet + stack;
+
+        let reopened = table.seats[seat]
+            .acted
+            .map_or(true, |level| level < table.full_bet);
+
+        if !reopened || all_in <= table.current_bet {
+            return None;
+        }
+
+        let min = table.current_bet + table.last_raise;
+
+        let (min, max) = match self {
+            Self::NoLimit => (min, all_in),
+            Self::PotLimit => {
+                let to_call = table.current_bet - bet;
+
+                (min, table.current_bet + table.pot() + to_call)
+            }
+            Self::FixedLimit { cap } => {
+                // The raise cap does not apply once the pot is heads-up.
+                if table.bets >= *cap && table.contenders().len() > 2 {
+                    return None;
+                }
+
+                let size = self.bet_size(table.street, table.config.big_blind);
+
+                (table.current_bet + size, table.current_bet + size)
+            }
+            Self::SpreadLimit {
+                min: low,
+                max: high,
+            } => (
+                table.current_bet + table.last_raise.max(*low).min(*high),
+                table.current_bet + high,
+            ),
+        };
+
+        let max = max.min(all_in);
+
+        Some((min.min(max), max))
+    }
+}
@@ -0,0 +1,212 @@
+use crate::card::Card;
+use crate::game::Hand;
+use crate::table::Event;
+use crate::table::SeatView;
+use crate::table::Street;
+use crate::table::Table;
+use crate::table::View;
+use crate::table::Viewer;
+
+impl SeatView {
+    pub fn stack(&self) -> u64 {
+        self.stack
+    }
+
+    pub fn bet(&self) -> u64 {
+        self.bet
+    }
+
+    pub fn committed(&self) -> u64 {
+        self.committed
+    }
+
+    pub fn is_folded(&self) -> bool {
+        self.folded
+    }
+
+    pub fn cards(&self) -> Option<Vec<Card>> {
+        self.cards.clone()
+    }
+}
+
+impl View {
+    pub fn viewer(&self) -> Viewer {
+        self.viewer
+    }
+
+    pub fn seats(&self) -> Vec<SeatView> {
+        self.seats.clone()
+    }
+
+    pub fn button(&self) -> usize {
+        self.button
+    }
+
+    pub fn boards(&self) -> Vec<Vec<Card>> {
+        self.boards.clone()
+    }
+
+    pub fn street(&self) -> Street {
+        self.street
+    }
+
+    pub fn to_act(&self) -> Option<usize> {
+        self.to_act
+    }
+
+    pub fn current_bet(&self) -> u64 {
+        self.current_bet
+    }
+
+    pub fn pot(&self) -> u64 {
+        self.pot
+    }
+
+    pub fn events(&self) -> Vec<Event> {
+        self.events.clone()
+    }
+}
+
+impl Table {
+    pub fn view(&self, viewer: Viewer) -> View {
+        let shown = self
+            .log
+            .iter()
+            .filter_map(|event| match event {
+                Event::Showdown { seat, cards: _ } => Some(*seat),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let visible = |seat: usize| viewer == Viewer::Player(seat) || shown.contains(&seat);
+
+        let seats = self
+            .seats
+            .iter()
+            .enumerate()
+            .map(|(n, seat)| SeatView {
+                stack: seat.stack,
+                bet: seat.bet,
+                committed: seat.committed,
+                folded: seat.folded,
+                cards: if visible(n) {
+                    Some(seat.hand.cards())
+                } else {
+                    None
+                },
+            })
+            .collect();
+
+        let events = self
+            .log
+            .iter()
+            .map(|event| match event {
+                // The seed would give away every card still in the deck.
+                Event::Start {
+                    config,
+                    stacks,
+                    button,
+                    seed: _,
+                } => Event::Start {
+                    config: *config,
+                    stacks: stacks.clone(),
+                    button: *button,
+                    seed: None,
+                },
+                Event::Deal { seat, cards: _ } if !visible(*seat) => Event::Deal {
+                    seat: *seat,
+                    cards: vec![],
+                },
+                event => event.clone(),
+            })
+            .collect();
+
+        View {
+            viewer,
+            seats,
+            button: self.button,
+            boards: self.boards(),
+            street: self.street,
+            to_act: self.to_act,
+            current_bet: self.current_bet,
+            pot: self.pot(),
+            events,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::card::Deck;
+    use crate::game::Hand;
+    use crate::table::Action;
+    use crate::table::Config;
+    use crate::table::Event;
+    use crate::table::Table;
+    use crate::table::Viewer;
+
+    fn table() -> Table {
+        Table::new(Config::new(1, 2), vec![100, 100, 100], 0, Deck::new(0)).unwrap()
+    }
+
+    fn dealt(events: &[Event]) -> Vec<usize> {
+        events
+            .iter()
+            .filter_map(|event| match event {
+                Event::Deal { seat, cards } if !cards.is_empty() => Some(*seat),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_player_view() {
+        let table = table();
+        let view = table.view(Viewer::Player(1));
+
+        assert_eq!(view.viewer(), Viewer::Player(1));
+        assert_eq!(view.seats()[0].cards(), None);
+        assert_eq!(
+            view.seats()[1].cards(),
+            Some(table.seats()[1].hand().cards())
+        );
+        assert_eq!(view.seats()[2].cards(), None);
+        assert_eq!(dealt(&view.events()), vec![1]);
+        assert_eq!(view.pot(), 3);
+        assert_eq!(view.to_act(), Some(0));
+    }
+
+    #[test]
+    fn test_spectator_view() {
+        let mut table = table();
+        let view = table.view(Viewer::Spectator);
+
+        assert!(view.seats().iter().all(|seat| seat.cards().is_none()));
+        assert!(dealt(&view.events()).is_empty());
+        assert!(matches!(
+            view.events()[0],
+            Event::Start {
+                config: _,
+                stacks: _,
+                button: 0,
+                seed: None
+            }
+        ));
+
+        table.act(Action::Fold).unwrap();
+        table.act(Action::Call).unwrap();
+        table.act(Action::Check).unwrap();
+
+        for _ in 0..6 {
+            table.act(Action::Check).unwrap();
+        }
+
+        let view = table.view(Viewer::Spectator);
+
+        assert_eq!(view.seats()[0].cards(), None);
+        assert!(view.seats()[1].cards().is_some());
+        assert!(view.seats()[2].cards().is_some());
+        assert_eq!(dealt(&view.events()), vec![1, 2]);
+        assert_eq!(view.boards()[0].len(), 5);
+    }
+}
@@ -0,0 +1,250 @@
+use std::io::Error;
+use std::io::ErrorKind;
+
+use ::itertools::Itertools;
+use ::rand::Rng;
+use ::rand::SeedableRng;
+use ::rand_chacha::ChaCha8Rng;
+
+use crate::tournament::Tournament;
+
+// Largest field for which every finishing order is enumerated.
+const EXACT_PLAYERS: usize = 16;
+
+impl Tournament {
+    pub fn icm(stacks: &[u64], payouts: &[u64]) -> Vec<f64> {
+        if stacks.len() <= EXACT_PLAYERS {
+            Self::icm_exact(stacks, payouts)
+        } else {
+            Self::icm_sampled(stacks, payouts, 100_000, 0)
+        }
+    }
+
+    // Malmuth-Harville: the chance of finishing next is proportional to
+    // the stack among players not yet placed. `prob[mask]` is the chance
+    // that exactly the players in `mask` took the top places. Once only
+    // empty stacks are left they are equally likely to finish next. The
+    // table grows as `2^players`, so `icm` only calls this for fields of
+    // up to `EXACT_PLAYERS`.
+    pub(crate) fn icm_exact(stacks: &[u64], payouts: &[u64]) -> Vec<f64> {
+        let players = stacks.len();
+        let places = payouts.len().min(players);
+        let total = stacks.iter().sum::<u64>() as f64;
+
+        let mut equity = vec![0.0; players];
+        let mut prob = vec![0.0; 1 << players];
+        prob[0] = 1.0;
+
+        for mask in 0..(1usize << players) {
+            let placed = mask.count_ones() as usize;
+
+            if prob[mask] == 0.0 || placed >= places {
+                continue;
+            }
+
+            let rest = total
+                - (0..players)
+                    .filter(|player| mask & (1 << player) != 0)
+                    .map(|player| stacks[player] as f64)
+                    .sum::<f64>();
+
+            let left = players - placed;
+
+            for player in (0..players).filter(|player| mask & (1 << player) == 0) {
+                let p = if rest > 0.0 {
+                    prob[mask] * stacks[player] as f64 / rest
+                } else {
+                    prob[mask] / left as f64
+                };
+
+                equity[player] += p * payouts[placed] as f64;
+                prob[mask | (1 << player)] += p;
+            }
+        }
+
+        equity
+    }
+
+    // Samples finishing orders with an exponential race, which orders
+    // players exactly as the Malmuth-Harville model does. Empty stacks
+    // finish after everyone else in a random order.
+    pub fn icm_sampled(stacks: &[u64], payouts: &[u64], samples: usize, seed: u64) -> Vec<f64> {
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let mut equity = vec![0.0; stacks.len()];
+
+        for _ in 0..samples {
+            let order = (0..stacks.len())
+                .map(|player| {
+                    let time = match stacks[player] {
+                        0 => f64::INFINITY,
+                        stack => -(1.0 - rng.gen::<f64>()).ln() / stack as f64,
+                    };
+
+                    ((time, rng.gen::<f64>()), player)
+                })
+                .sorted_by(|(a, _), (b, _)| a.partial_cmp(b).unwrap())
+                .map(|(_, player)| player);
+
+            for (place, player) in order.enumerate().take(payouts.len()) {
+                equity[player] += payouts[place] as f64;
+            }
+        }
+
+        equity
+            .into_iter()
+            .map(|equity| equity / samples as f64)
+            .collect()
+    }
+
+    // Every player locks up the lowest remaining payout and the rest is
+    // split by chip count.
+    pub fn chip_chop(stacks: &[u64], payouts: &[u64]) -> Result<Vec<u64>, Error> {
+        if payouts.windows(2).any(|pair| pair[0] < pair[1]) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "payouts have to be sorted from first place down",
+            ));
+        }
+
+        if stacks.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let players = stacks.len();
+        let payouts = &payouts[..payouts.len().min(players)];
+        let floor = if payouts.len() == players {
+            payouts[players - 1]
+        } else {
+            0
+        };
+
+        let total = payouts.iter().sum::<u64>();
+        let chips = stacks.iter().sum::<u64>() as f64;
+        let rest = (total - floor * players as u64) as f64;
+
+        // Without any chips in play the rest is split evenly.
+        let share = |stack: u64| {
+            if chips > 0.0 {
+                stack as f64 / chips
+            } else {
+                1.0 / players as f64
+            }
+        };
+
+        Ok(Self::apportion(
+            &stacks
+                .iter()
+                .map(|stack| floor as f64 + rest * share(*stack))
+                .collect_vec(),
+            total,
+        ))
+    }
+
+    pub fn icm_deal(stacks: &[u64], payouts: &[u64]) -> Vec<u64> {
+        let total = payouts.iter().take(stacks.len()).sum::<u64>();
+
+        Self::apportion(&Self::icm(stacks, payouts), total)
+    }
+
+    // Rounds shares down and hands out what is left by largest remainder.
+    fn apportion(shares: &[f64], total: u64) -> Vec<u64> {
+        let mut amounts = shares.iter().map(|share| *share as u64).collect_vec();
+        let left = total.saturating_sub(amounts.iter().sum::<u64>()) as usize;
+
+        (0..shares.len())
+            .sorted_by(|a, b| {
+                (shares[*b] - shares[*b].floor())
+                    .partial_cmp(&(shares[*a] - shares[*a].floor()))
+                    .unwrap()
+                    .then(a.cmp(b))
+            })
+            .take(left)
+            .collect_vec()
+            .into_iter()
+            .for_each(|player| amounts[player] += 1);
+
+        amounts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use crate::tournament::Tournament;
+
+    #[test]
+    fn test_icm_exact() {
+        let equity = Tournament::icm_exact(&[5000, 3000, 2000], &[50, 30, 20]);
+
+        assert_lt!((equity[0] - 38.393).abs(), 0.001);
+        assert_lt!((equity.iter().sum::<f64>() - 100.0).abs(), 1e-9);
+        assert_eq!(Tournament::icm_exact(&[75, 25], &[100]), vec![75.0, 25.0]);
+    }
+
+    #[test]
+    fn test_icm_empty_stacks() {
+        assert_eq!(
+            Tournament::icm_exact(&[0, 0, 0], &[60, 30]),
+            vec![30.0, 30.0, 30.0]
+        );
+
+        let equity = Tournament::icm_exact(&[100, 0, 0], &[50, 30, 20]);
+
+        assert_eq!(equity, vec![50.0, 25.0, 25.0]);
+        assert_eq!(Tournament::icm_deal(&[0, 0], &[70, 30]), vec![50, 50]);
+        assert_eq!(
+            Tournament::chip_chop(&[0, 0], &[70, 30]).unwrap(),
+            vec![50, 50]
+        );
+
+        Tournament::icm_sampled(&[100, 0, 0], &[50, 30, 20], 10_000, 0)
+            .iter()
+            .zip(equity.iter())
+            .for_each(|(a, b)| assert_lt!((a - b).abs(), 1.0));
+    }
+
+    #[test]
+    fn test_icm_sampled() {
+        let exact = Tournament::icm_exact(&[5000, 3000, 2000, 1000], &[50, 30, 20]);
+        let sampled = Tournament::icm_sampled(&[5000, 3000, 2000, 1000], &[50, 30, 20], 50_000, 0);
+
+        exact
+            .iter()
+            .zip(sampled.iter())
+            .for_each(|(a, b)| assert_lt!((a - b).abs(), 0.5));
+    }
+
+    #[test]
+    fn test_icm_large_field() {
+        let equity = Tournament::icm(&[1000; 20], &[400, 250, 150, 100, 100]);
+
+        assert_lt!((equity.iter().sum::<f64>() - 1000.0).abs(), 1e-6);
+        equity
+            .iter()
+            .for_each(|equity| assert_lt!((equity - 50.0).abs(), 1.0));
+    }
+
+    #[test]
+    fn test_chip_chop() {
+        assert_eq!(
+            Tournament::chip_chop(&[5000, 3000, 2000], &[50, 30, 20]).unwrap(),
+            vec![40, 32, 28]
+        );
+        assert_eq!(
+            Tournament::chip_chop(&[600, 400], &[100, 60, 40]).unwrap(),
+            vec![84, 76]
+        );
+        assert_eq!(Tournament::chip_chop(&[], &[]).unwrap(), vec![]);
+        assert_eq!(Tournament::chip_chop(&[], &[50, 30]).unwrap(), vec![]);
+        assert_err!(Tournament::chip_chop(&[600, 400], &[40, 60, 100]));
+    }
+
+    #[test]
+    fn test_icm_deal() {
+        let deal = Tournament::icm_deal(&[5000, 3000, 2000], &[50, 30, 20]);
+
+        assert_eq!(deal.iter().sum::<u64>(), 100);
+        assert_eq!(deal[0], 38);
+    }
+}
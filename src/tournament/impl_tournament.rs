@@ -0,0 +1,286 @@
+use std::io::Error;
+use std::io::ErrorKind;
+
+use ::itertools::Itertools;
+
+use crate::table::Config;
+use crate::tournament::Level;
+use crate::tournament::Tournament;
+
+impl Level {
+    pub fn new(small_blind: u64, big_blind: u64, hands: usize) -> Self {
+        Self {
+            small_blind,
+            big_blind,
+            ante: None,
+            hands,
+        }
+    }
+}
+
+impl Tournament {
+    pub fn new(levels: Vec<Level>, stacks: Vec<u64>, payouts: Vec<u64>) -> Result<Self, Error> {
+        if levels.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "no levels"));
+        }
+
+        if stacks.len() < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "at least two players are required",
+            ));
+        }
+
+        if stacks.contains(&0) {
+            return Err(Error::new(ErrorKind::InvalidInput, "empty stack"));
+        }
+
+        Ok(Self {
+            levels,
+            places: vec![None; stacks.len()],
+            stacks,
+            payouts,
+            hands: 0,
+        })
+    }
+
+    // Splits a prize pool by percentages in basis points; rounding
+    // leftovers go to first place.
+    pub fn payout_table(prize_pool: u64, percentages: &[u64]) -> Result<Vec<u64>, Error> {
+        if percentages.iter().sum::<u64>() != 10_000 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "percentages have to add up to 100%",
+            ));
+        }
+
+        let mut payouts = percentages
+            .iter()
+            .map(|percent| prize_pool * percent / 10_000)
+            .collect::<Vec<_>>();
+
+        payouts[0] += prize_pool - payouts.iter().sum::<u64>();
+
+        Ok(payouts)
+    }
+
+    pub fn stacks(&self) -> Vec<u64> {
+        self.stacks.clone()
+    }
+
+    pub fn alive(&self) -> Vec<usize> {
+        (0..self.stacks.len())
+            .filter(|player| self.places[*player].is_none())
+            .collect()
+    }
+
+    pub fn places(&self) -> Vec<Option<usize>> {
+        self.places.clone()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.alive().is_empty()
+    }
+
+    pub fn level(&self) -> usize {
+        let mut hands = self.hands;
+
+        for (n, level) in self.levels.iter().enumerate() {
+            if hands < level.hands {
+                return n;
+            }
+
+            hands -= level.hands;
+        }
+
+        self.levels.len() - 1
+    }
+
+    pub fn config(&self) -> Config {
+        let level = self.levels[self.level()];
+
+        Config {
+            ante: level.ante,
+            ..Config::new(level.small_blind, level.big_blind)
+        }
+    }
+
+    // Takes every player's stack after a hand. Players who bust on the
+    // same hand finish in order of their stacks at the start of it, and
+    // share places when those stacks are equal.
+    pub fn play_hand(&mut self, stacks: Vec<u64>) -> Result<Vec<usize>, Error> {
+        if self.is_complete() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "tournament is complete",
+            ));
+        }
+
+        if stacks.len() != self.stacks.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "wrong number of players",
+            ));
+        }
+
+        if stacks.iter().sum::<u64>() != self.stacks.iter().sum::<u64>() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "chip count has changed",
+            ));
+        }
+
+        if (0..stacks.len()).any(|player| self.places[player].is_some() && stacks[player] > 0) {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "eliminated players cannot have chips",
+            ));
+        }
+
+        let busted = self
+            .alive()
+            .into_iter()
+            .filter(|player| stacks[*player] == 0)
+            .collect::<Vec<_>>();
+
+        let remaining = self.alive().len() - busted.len();
+
+        for player in busted.iter() {
+            let above = busted
+                .iter()
+                .filter(|other| self.stacks[**other] > self.stacks[*player])
+                .count();
+
+            self.places[*player] = Some(remaining + above + 1);
+        }
+
+        if remaining == 1 {
+            let winner = (0..stacks.len())
+                .find(|player| stacks[*player] > 0)
+                .unwrap();
+
+            self.places[winner] = Some(1);
+        }
+
+        self.stacks = stacks;
+        self.hands += 1;
+
+        Ok(busted)
+    }
+
+    pub fn payouts(&self) -> Vec<u64> {
+        let mut payouts = vec![0; self.stacks.len()];
+
+        let tied = (0..self.stacks.len())
+            .filter_map(|player| self.places[player].map(|place| (place, player)))
+            .into_group_map();
+
+        for (place, players) in tied {
+            let total = (place - 1..place - 1 + players.len())
+                .map(|n| self.payouts.get(n).copied().unwrap_or(0))
+                .sum::<u64>();
+
+            let share = total / players.len() as u64;
+            let odd_chips = total % players.len() as u64;
+
+            players.iter().sorted().enumerate().for_each(|(n, player)| {
+                payouts[*player] = share + if (n as u64) < odd_chips { 1 } else { 0 };
+            });
+        }
+
+        payouts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::claim::*;
+
+    use crate::table::Ante;
+    use crate::tournament::Level;
+    use crate::tournament::Tournament;
+
+    fn tournament() -> Tournament {
+        let levels = vec![
+            Level::new(1, 2, 2),
+            Level {
+                ante: Some(Ante::BigBlind(4)),
+                ..Level::new(2, 4, 2)
+            },
+        ];
+        let payouts = Tournament::payout_table(1000, &[5000, 3000, 2000]).unwrap();
+
+        Tournament::new(levels, vec![100, 100, 50, 50, 100], payouts).unwrap()
+    }
+
+    #[test]
+    fn test_payout_table() {
+        assert_eq!(
+            Tournament::payout_table(1001, &[6000, 4000]).unwrap(),
+            vec![601, 400]
+        );
+        assert_err!(Tournament::payout_table(1000, &[6000, 3000]));
+    }
+
+    #[test]
+    fn test_levels() {
+        let mut tournament = tournament();
+
+        assert_eq!(tournament.level(), 0);
+        assert_eq!(tournament.config().big_blind, 2);
+        assert_eq!(tournament.config().ante, None);
+
+        for _ in 0..2 {
+            assert_ok!(tournament.play_hand(tournament.stacks()));
+        }
+
+        assert_eq!(tournament.level(), 1);
+        assert_eq!(tournament.config().ante, Some(Ante::BigBlind(4)));
+
+        for _ in 0..5 {
+            assert_ok!(tournament.play_hand(tournament.stacks()));
+        }
+
+        assert_eq!(tournament.level(), 1);
+    }
+
+    #[test]
+    fn test_eliminations() {
+        let mut tournament = tournament();
+
+        assert_err!(tournament.play_hand(vec![100, 100, 50, 50, 101]));
+        assert_eq!(
+            tournament.play_hand(vec![150, 150, 0, 0, 100]).unwrap(),
+            vec![2, 3]
+        );
+        assert_eq!(
+            tournament.places(),
+            vec![None, None, Some(4), Some(4), None]
+        );
+
+        assert_err!(tournament.play_hand(vec![150, 150, 0, 100, 0]));
+        assert_eq!(
+            tournament.play_hand(vec![400, 0, 0, 0, 0]).unwrap(),
+            vec![1, 4]
+        );
+        assert_eq!(
+            tournament.places(),
+            vec![Some(1), Some(2), Some(4), Some(4), Some(3)]
+        );
+        assert!(tournament.is_complete());
+        assert_err!(tournament.play_hand(vec![400, 0, 0, 0, 0]));
+
+        assert_eq!(tournament.payouts(), vec![500, 300, 0, 0, 200]);
+    }
+
+    #[test]
+    fn test_tied_payouts() {
+        let payouts = Tournament::payout_table(1001, &[5000, 3000, 2000]).unwrap();
+        let mut tournament =
+            Tournament::new(vec![Level::new(1, 2, 10)], vec![100, 100, 100], payouts).unwrap();
+
+        assert_eq!(tournament.play_hand(vec![300, 0, 0]).unwrap(), vec![1, 2]);
+        assert_eq!(tournament.places(), vec![Some(1), Some(2), Some(2)]);
+        assert_eq!(tournament.payouts(), vec![501, 250, 250]);
+    }
+}
@@ -0,0 +1,39 @@
+mod icm;
+mod impl_tournament;
+mod seating;
+
+use ::rand_chacha::ChaCha8Rng;
+
+use crate::table::Ante;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Level {
+    pub small_blind: u64,
+    pub big_blind: u64,
+    pub ante: Option<Ante>,
+    pub hands: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tournament {
+    pub(crate) levels: Vec<Level>,
+    pub(crate) stacks: Vec<u64>,
+    pub(crate) places: Vec<Option<usize>>,
+    pub(crate) payouts: Vec<u64>,
+    pub(crate) hands: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Move {
+    pub player: usize,
+    pub from: (usize, usize),
+    pub to: (usize, usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct Seating {
+    pub(crate) tables: Vec<Vec<Option<usize>>>,
+    pub(crate) buttons: Vec<Option<usize>>,
+    pub(crate) size: usize,
+    pub(crate) rng: ChaCha8Rng,
+}
@@ -0,0 +1,373 @@
+use std::io::Error;
+use std::io::ErrorKind;
+
+use ::itertools::Itertools;
+use ::rand::seq::SliceRandom;
+use ::rand::Rng;
+use ::rand::SeedableRng;
+use ::rand_chacha::ChaCha8Rng;
+
+use crate::tournament::Move;
+use crate::tournament::Seating;
+
+impl Seating {
+    pub fn new(players: usize, size: usize, seed: u64) -> Result<Self, Error> {
+        if size < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "tables need at least two seats",
+            ));
+        }
+
+        if players < 2 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "at least two players are required",
+            ));
+        }
+
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let count = players.div_ceil(size);
+
+        let mut draw = (0..players).collect_vec();
+        draw.shuffle(&mut rng);
+
+        let mut tables = vec![vec![None; size]; count];
+
+        for (n, player) in draw.into_iter().enumerate() {
+            tables[n % count][n / count] = Some(player);
+        }
+
+        let mut seating = Self {
+            tables: vec![vec![]; count],
+            buttons: vec![None; count],
+            size,
+            rng,
+        };
+
+        for (n, mut table) in tables.into_iter().enumerate() {
+            table.shuffle(&mut seating.rng);
+            seating.seat_table(n, table);
+        }
+
+        Ok(seating)
+    }
+
+    pub fn tables(&self) -> Vec<Vec<Option<usize>>> {
+        self.tables.clone()
+    }
+
+    pub fn active(&self) -> Vec<usize> {
+        (0..self.tables.len())
+            .filter(|table| self.count(*table) > 0)
+            .collect()
+    }
+
+    pub fn players(&self, table: usize) -> Vec<usize> {
+        self.tables[table].iter().flatten().copied().collect()
+    }
+
+    // Broken tables have no button.
+    pub fn button(&self, table: usize) -> Option<usize> {
+        self.buttons[table]
+    }
+
+    pub fn find(&self, player: usize) -> Option<(usize, usize)> {
+        self.tables.iter().enumerate().find_map(|(table, seats)| {
+            seats
+                .iter()
+                .position(|seat| *seat == Some(player))
+                .map(|seat| (table, seat))
+        })
+    }
+
+    pub fn eliminate(&mut self, player: usize) -> Result<(), Error> {
+        let (table, seat) = self
+            .find(player)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidInput, "player is not seated"))?;
+
+        self.tables[table][seat] = None;
+        self.close_if_empty(table);
+
+        Ok(())
+    }
+
+    pub fn next_hand(&mut self, table: usize) {
+        if let Some(seat) = self.buttons[table].and_then(|button| self.next_occupied(table, button))
+        {
+            self.buttons[table] = Some(seat);
+        }
+    }
+
+    // Breaks tables while the field fits on fewer of them, redraws the
+    // final table, then balances by moving the player due the big blind
+    // from the largest table into the seat at the smallest table that
+    // gets the big blind soonest.
+    pub fn rebalance(&mut self) -> Vec<Move> {
+        let mut moves = vec![];
+        let total = self
+            .active()
+            .iter()
+            .map(|table| self.count(*table))
+            .sum::<usize>();
+
+        if self.active().len() > 1 && total <= self.size {
+            return self.final_table();
+        }
+
+        while self.active().len() > 1 && total <= (self.active().len() - 1) * self.size {
+            let broken = *self.active().last().unwrap();
+            let mut players = self.players(broken);
+            players.shuffle(&mut self.rng);
+
+            for player in players {
+                let to = self.smallest(broken);
+                moves.push(self.move_player(player, to));
+            }
+        }
+
+        loop {
+            let active = self.active();
+            let largest = active
+                .iter()
+                .copied()
+                .max_by_key(|table| (self.count(*table), std::cmp::Reverse(*table)));
+            let smallest = active
+                .iter()
+                .copied()
+                .min_by_key(|table| (self.count(*table), *table));
+
+            match (largest, smallest) {
+                (Some(from), Some(to)) if self.count(from) > self.count(to) + 1 => {
+                    let seat = self.next_big_blind(from);
+                    let player = self.tables[from][seat].unwrap();
+
+                    moves.push(self.move_player(player, to));
+                }
+                _ => return moves,
+            }
+        }
+    }
+
+    pub(crate) fn next_big_blind(&self, table: usize) -> usize {
+        let button = self
+            .next_occupied(table, self.buttons[table].unwrap())
+            .unwrap();
+        let small_blind = self.next_occupied(table, button).unwrap();
+
+        if self.count(table) == 2 {
+            small_blind
+        } else {
+            self.next_occupied(table, small_blind).unwrap()
+        }
+    }
+
+    // The final table is redrawn into the slot of the lowest active table,
+    // so redraws never grow the list of tables.
+    fn final_table(&mut self) -> Vec<Move> {
+        let table = self.active()[0];
+        let mut players = self
+            .active()
+            .into_iter()
+            .flat_map(|table| self.players(table))
+            .collect_vec();
+        players.shuffle(&mut self.rng);
+
+        let from = players
+            .iter()
+            .map(|player| self.find(*player).unwrap())
+            .collect_vec();
+        let mut seats = (0..self.size).collect_vec();
+        seats.shuffle(&mut self.rng);
+
+        self.tables.iter_mut().for_each(|table| table.clear());
+        self.buttons.iter_mut().for_each(|button| *button = None);
+
+        let mut seated = vec![None; self.size];
+
+        for (player, seat) in players.iter().zip(seats.iter()) {
+            seated[*seat] = Some(*player);
+        }
+
+        self.seat_table(table, seated);
+
+        players
+            .into_iter()
+            .zip(from)
+            .zip(seats)
+            .map(|((player, from), seat)| Move {
+                player,
+                from,
+                to: (table, seat),
+            })
+            .collect()
+    }
+
+    fn seat_table(&mut self, table: usize, seats: Vec<Option<usize>>) {
+        let occupied = (0..seats.len())
+            .filter(|seat| seats[*seat].is_some())
+            .collect_vec();
+        let button = occupied[self.rng.gen_range(0..occupied.len())];
+
+        self.tables[table] = seats;
+        self.buttons[table] = Some(button);
+    }
+
+    fn close_if_empty(&mut self, table: usize) {
+        if self.count(table) == 0 {
+            self.tables[table].clear();
+            self.buttons[table] = None;
+        }
+    }
+
+    fn move_player(&mut self, player: usize, to: usize) -> Move {
+        let from = self.find(player).unwrap();
+        let big_blind = self.next_big_blind(to);
+
+        let seat = (1..=self.size)
+            .map(|n| (big_blind + n) % self.size)
+            .find(|seat| self.tables[to][*seat].is_none())
+            .unwrap();
+
+        self.tables[from.0][from.1] = None;
+        self.tables[to][seat] = Some(player);
+        self.close_if_empty(from.0);
+
+        Move {
+            player,
+            from,
+            to: (to, seat),
+        }
+    }
+
+    fn smallest(&self, except: usize) -> usize {
+        self.active()
+            .into_iter()
+            .filter(|table| *table != except)
+            .min_by_key(|table| (self.count(*table), *table))
+            .unwrap()
+    }
+
+    fn count(&self, table: usize) -> usize {
+        self.tables[table].iter().flatten().count()
+    }
+
+    fn next_occupied(&self, table: usize, from: usize) -> Option<usize> {
+        let size = self.tables[table].len();
+
+        (1..=size)
+            .map(|n| (from + n) % size)
+            .find(|seat| self.tables[table][*seat].is_some())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use ::claim::*;
+
+    use crate::tournament::Seating;
+
+    fn counts(seating: &Seating) -> Vec<usize> {
+        seating
+            .active()
+            .into_iter()
+            .map(|table| seating.players(table).len())
+            .collect()
+    }
+
+    #[test]
+    fn test_new() {
+        let seating = Seating::new(20, 9, 0).unwrap();
+
+        assert_eq!(counts(&seating), vec![7, 7, 6]);
+        assert_eq!(
+            seating
+                .tables()
+                .concat()
+                .into_iter()
+                .flatten()
+                .collect::<HashSet<_>>()
+                .len(),
+            20
+        );
+        assert_eq!(seating.tables(), Seating::new(20, 9, 0).unwrap().tables());
+        assert_ne!(seating.tables(), Seating::new(20, 9, 1).unwrap().tables());
+        assert_err!(Seating::new(20, 1, 0));
+    }
+
+    #[test]
+    fn test_balance() {
+        let mut seating = Seating::new(25, 9, 0).unwrap();
+
+        for player in seating.players(2).into_iter().take(2) {
+            assert_ok!(seating.eliminate(player));
+        }
+
+        let big_blind = seating.next_big_blind(0);
+        let player = seating.tables()[0][big_blind].unwrap();
+        let moves = seating.rebalance();
+
+        assert_eq!(moves.len(), 1);
+        assert_eq!(moves[0].player, player);
+        assert_eq!(moves[0].from, (0, big_blind));
+        assert_eq!(moves[0].to.0, 2);
+        assert_eq!(counts(&seating), vec![8, 8, 7]);
+        assert!(seating.rebalance().is_empty());
+    }
+
+    #[test]
+    fn test_break_table() {
+        let mut seating = Seating::new(20, 9, 0).unwrap();
+
+        for player in seating.players(0).into_iter().take(2) {
+            assert_ok!(seating.eliminate(player));
+        }
+
+        let moves = seating.rebalance();
+
+        assert_eq!(seating.active(), vec![0, 1]);
+        assert_eq!(counts(&seating), vec![9, 9]);
+        assert_none!(seating.button(2));
+        assert!(moves.iter().filter(|m| m.from.0 == 2).count() == 6);
+    }
+
+    #[test]
+    fn test_final_table() {
+        let mut seating = Seating::new(20, 9, 0).unwrap();
+
+        for player in (0..11).collect::<Vec<_>>() {
+            assert_ok!(seating.eliminate(player));
+        }
+
+        let moves = seating.rebalance();
+
+        assert_eq!(moves.len(), 9);
+        assert_eq!(seating.active(), vec![0]);
+        assert_eq!(counts(&seating), vec![9]);
+        assert_eq!(seating.tables().len(), 3);
+        assert_some!(seating.button(0));
+        assert_none!(seating.button(1));
+        assert_none!(seating.button(2));
+        assert_err!(seating.eliminate(0));
+
+        for player in seating.players(0).into_iter().take(3) {
+            assert_ok!(seating.eliminate(player));
+        }
+
+        assert!(seating.rebalance().is_empty());
+        assert_eq!(seating.tables().len(), 3);
+    }
+
+    #[test]
+    fn test_next_hand() {
+        let mut seating = Seating::new(4, 9, 0).unwrap();
+        let button = seating.button(0).unwrap();
+
+        seating.next_hand(0);
+
+        assert_ne!(seating.button(0).unwrap(), button);
+        assert!(seating.tables()[0][seating.button(0).unwrap()].is_some());
+    }
+}